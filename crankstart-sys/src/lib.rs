@@ -75,12 +75,16 @@ include!("bindings_aarch64.rs");
 #[cfg(target_os = "none")]
 include!("bindings_playdate.rs");
 
+/// `top` is the rect's minimum y and `bottom` its maximum, matching how the SDK reads an
+/// `LCDRect` and the reverse conversion below. Earlier versions swapped the two, giving
+/// rects with a negative height; code that worked around that by swapping them back
+/// needs to stop.
 #[cfg(feature = "euclid")]
 impl From<euclid::default::Rect<i32>> for LCDRect {
     fn from(r: euclid::default::Rect<i32>) -> Self {
         LCDRect {
-            top: r.max_y(),
-            bottom: r.min_y(),
+            top: r.min_y(),
+            bottom: r.max_y(),
             left: r.min_x(),
             right: r.max_x(),
         }
@@ -112,3 +116,46 @@ impl From<PDRect> for euclid::default::Rect<f32> {
         euclid::rect(r.x, r.y, r.width, r.height)
     }
 }
+
+/// Rounds towards negative infinity, since `core` has no `f32::floor`.
+pub fn floor_to_i32(value: f32) -> i32 {
+    let truncated = value as i32;
+    if (truncated as f32) > value {
+        truncated - 1
+    } else {
+        truncated
+    }
+}
+
+/// Rounds towards positive infinity, since `core` has no `f32::ceil`.
+pub fn ceil_to_i32(value: f32) -> i32 {
+    let truncated = value as i32;
+    if (truncated as f32) < value {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+impl From<LCDRect> for PDRect {
+    fn from(r: LCDRect) -> Self {
+        PDRect {
+            x: r.left as f32,
+            y: r.top as f32,
+            width: (r.right - r.left) as f32,
+            height: (r.bottom - r.top) as f32,
+        }
+    }
+}
+
+/// Rounds outwards to whole pixels, so the `LCDRect` covers all of the `PDRect`.
+impl From<PDRect> for LCDRect {
+    fn from(r: PDRect) -> Self {
+        LCDRect {
+            left: floor_to_i32(r.x),
+            right: ceil_to_i32(r.x + r.width),
+            top: floor_to_i32(r.y),
+            bottom: ceil_to_i32(r.y + r.height),
+        }
+    }
+}
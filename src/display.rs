@@ -1,11 +1,13 @@
 //! Display-related functions
 use crate::{
     geometry::{ScreenPoint, ScreenSize},
+    graphics::{LCD_COLUMNS, LCD_ROWS},
     pd_func_caller,
+    system::System,
 };
 use anyhow::Error;
 use core::ptr;
-use euclid::{default::Vector2D, size2};
+use euclid::{default::Vector2D, size2, vec2};
 
 /// Global Display handle
 #[derive(Clone, Debug)]
@@ -25,6 +27,8 @@ impl Display {
 
     /// Get the current screen size, taking scale into account
     /// e.g. if the current scale is `2`, this returns `200x120` instead of `400x240`
+    ///
+    /// Use [Display::get_width()] and [Display::get_height()] for the hardware size instead.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/2.1.1/Inside%20Playdate%20with%20C.html#f-display.getHeight)
     pub fn get_size(&self) -> Result<ScreenSize, Error> {
@@ -34,6 +38,24 @@ impl Display {
         ))
     }
 
+    /// Get the width of the display hardware in pixels, always `400` whatever the scale.
+    ///
+    /// Use [Display::get_size()] for the width after scaling.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_width(&self) -> i32 {
+        LCD_COLUMNS as i32
+    }
+
+    /// Get the height of the display hardware in pixels, always `240` whatever the scale.
+    ///
+    /// Use [Display::get_size()] for the height after scaling.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_height(&self) -> i32 {
+        LCD_ROWS as i32
+    }
+
     /// If set to `true`, the frame buffer is drawn inverted—black instead of white, and vice versa.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-display.setInverted)
@@ -48,7 +70,21 @@ impl Display {
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-display.setScale)
     pub fn set_scale(&self, scale_factor: u32) -> Result<(), Error> {
         debug_assert!(scale_factor == 1 || scale_factor == 2 || scale_factor == 4 || scale_factor == 8, "scale_factor must be 1/2/4/8");
-        pd_func_caller!((*self.0).setScale, scale_factor)
+        pd_func_caller!((*self.0).setScale, scale_factor)?;
+        unsafe {
+            SCALE = scale_factor;
+        }
+        Ok(())
+    }
+
+    /// Gets the display scale factor: 1, 2, 4, or 8.
+    ///
+    /// The C API has no getter for this, so this returns the last scale passed to
+    /// [Display::set_scale()], or 1 if it hasn't been called.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_scale(&self) -> Result<u32, Error> {
+        Ok(unsafe { SCALE })
     }
 
     /// Adds a mosaic effect to the display.
@@ -73,7 +109,21 @@ impl Display {
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-display.setRefreshRate)
     pub fn set_refresh_rate(&self, rate: f32) -> Result<(), Error> {
-        pd_func_caller!((*self.0).setRefreshRate, rate)
+        pd_func_caller!((*self.0).setRefreshRate, rate)?;
+        unsafe {
+            REFRESH_RATE = rate;
+        }
+        Ok(())
+    }
+
+    /// Gets the nominal refresh rate in frames per second.
+    ///
+    /// The C API has no getter for this, so this returns the last rate passed to
+    /// [Display::set_refresh_rate()], or 30 (the rate games start at) if it hasn't been called.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_refresh_rate(&self) -> Result<f32, Error> {
+        Ok(unsafe { REFRESH_RATE })
     }
 
     /// Flips the display on the x or y axis, or both.
@@ -85,3 +135,84 @@ impl Display {
 }
 
 static mut DISPLAY: Display = Display(ptr::null_mut());
+static mut REFRESH_RATE: f32 = 30.0;
+static mut SCALE: u32 = 1;
+
+/// Which way a [MosaicTransition] runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MosaicDirection {
+    /// Materialize: start fully mosaicked and resolve to a clear image.
+    In,
+    /// Dematerialize: start clear and end fully mosaicked.
+    Out,
+}
+
+/// Animates [Display::set_mosaic()] over time for a "materialize/dematerialize" transition.
+///
+/// Call [MosaicTransition::update()] once per frame until [MosaicTransition::finished()]
+/// returns `true`. Both axes step through the valid mosaic range of 0-3 together.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct MosaicTransition {
+    start_ms: usize,
+    duration_ms: usize,
+    direction: MosaicDirection,
+    elapsed_ms: usize,
+}
+
+impl MosaicTransition {
+    /// The largest mosaic amount the display supports.
+    pub const MAX_AMOUNT: u32 = 3;
+
+    /// Start a transition lasting `duration_ms` milliseconds from now.
+    pub fn new(duration_ms: usize, direction: MosaicDirection) -> Result<Self, Error> {
+        let now = System::get().get_current_time_milliseconds()?;
+        Ok(Self::starting_at(now, duration_ms, direction))
+    }
+
+    /// Start a transition lasting `duration_ms` milliseconds from `start_ms`, as returned by
+    /// [System::get_current_time_milliseconds()].
+    pub fn starting_at(start_ms: usize, duration_ms: usize, direction: MosaicDirection) -> Self {
+        Self {
+            start_ms,
+            duration_ms,
+            direction,
+            elapsed_ms: 0,
+        }
+    }
+
+    /// The mosaic amount, 0-3, for the time `now_ms`.
+    pub fn amount_at(&self, now_ms: usize) -> u32 {
+        let elapsed = now_ms.saturating_sub(self.start_ms);
+        let progress = if self.duration_ms == 0 || elapsed >= self.duration_ms {
+            1.0
+        } else {
+            elapsed as f32 / self.duration_ms as f32
+        };
+        let amount = ((progress * Self::MAX_AMOUNT as f32) + 0.5) as u32;
+        let amount = amount.min(Self::MAX_AMOUNT);
+        match self.direction {
+            MosaicDirection::In => Self::MAX_AMOUNT - amount,
+            MosaicDirection::Out => amount,
+        }
+    }
+
+    /// Apply the mosaic amount for the current time to the display.
+    pub fn update(&mut self) -> Result<(), Error> {
+        let now = System::get().get_current_time_milliseconds()?;
+        self.update_at(now)
+    }
+
+    /// Like [MosaicTransition::update()], with the current time supplied by the caller.
+    pub fn update_at(&mut self, now_ms: usize) -> Result<(), Error> {
+        self.elapsed_ms = now_ms.saturating_sub(self.start_ms);
+        let amount = self.amount_at(now_ms);
+        Display::get().set_mosaic(vec2(amount, amount))
+    }
+
+    /// Returns `true` once an update has been applied at or after the end of the transition.
+    pub fn finished(&self) -> bool {
+        self.elapsed_ms >= self.duration_ms
+    }
+}
@@ -3,16 +3,21 @@
 use crankstart_sys::PDDateTime;
 //#![warn(missing_docs)]
 use {
-    crate::{log_to_console, pd_func_caller, pd_func_caller_log},
+    crate::{log_to_console, pd_func_caller, pd_func_caller_log, system::System},
     alloc::{boxed::Box, format, string::String, vec::Vec},
-    anyhow::{ensure, Error},
-    core::ptr,
+    anyhow::{anyhow, ensure, Error},
+    core::{convert::TryFrom, ptr},
     crankstart_sys::{ctypes::c_void, FileOptions, PDButtons, SDFile},
     cstr_core::CStr,
     cstr_core::CString,
     bitflags::bitflags,
 };
 
+pub mod buffile;
+pub use buffile::BufFile;
+pub mod savedata;
+pub use savedata::{SaveReader, SaveValue, SaveWriter};
+
 /// Information about a file retrieved via [FileSystem::stat()]
 ///
 /// This is a high level wrapper around [crankstart_sys::FileStat], and can be converted to/from it at will.
@@ -47,19 +52,21 @@ impl FileStat {
     /// # Ok(()) }
     /// ```
     pub fn last_modified_pddatetime(&self) -> PDDateTime {
-        // calculate the weekday, since PDDateTime has it for some reason. using the algorithm described here:
-        // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week
-        // d = day of month (1-31)
-        // m = month of the year (1-12)
-        // y = year
-        // c = the century mod 4
-        // w = (d + m + y + c) mod 7
-        // this calculates 0-6 instead of 1-7, so add 1 at the end
-        let d = self.inner.m_day;
-        let m = self.inner.m_month;
-        let y = self.inner.m_year;
-        let c = (y/1000)%4;
-        let weekday = ((d+m+y+c)%7)+1;
+        // calculate the weekday, since PDDateTime has it for some reason, using Sakamoto's method:
+        // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Sakamoto's_methods
+        // this gives 0 for Sunday through 6 for Saturday, while PDDateTime counts
+        // 1 for Monday through 7 for Sunday
+        const MONTH_OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let d = self.inner.m_day.clamp(1, 31);
+        let m = self.inner.m_month.clamp(1, 12);
+        let y = self.inner.m_year - if m < 3 { 1 } else { 0 };
+        // rem_euclid so a bogus year before 0 can't produce a negative weekday
+        let day_of_week = (y + y.div_euclid(4) - y.div_euclid(100)
+            + y.div_euclid(400)
+            + MONTH_OFFSETS[(m - 1) as usize]
+            + d)
+            .rem_euclid(7);
+        let weekday = if day_of_week == 0 { 7 } else { day_of_week };
 
         PDDateTime {
             year: self.inner.m_year as u16,
@@ -67,10 +74,37 @@ impl FileStat {
             day: self.inner.m_day as u8,
             weekday: weekday as u8,
             hour: self.inner.m_hour as u8,
-            minute: self.inner.m_hour as u8,
+            minute: self.inner.m_minute as u8,
             second: self.inner.m_second as u8
         }
     }
+
+    /// Return the last time the file was modified as seconds since the Playdate epoch
+    /// (midnight, January 1 2000), using [System::convert_datetime_to_epoch()].
+    ///
+    /// Returns an error if the stored modification time isn't a valid date and time.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn modified_epoch(&self) -> Result<u32, Error> {
+        let stat = &self.inner;
+        ensure!(
+            (1..=12).contains(&stat.m_month)
+                && (1..=31).contains(&stat.m_day)
+                && (0..24).contains(&stat.m_hour)
+                && (0..60).contains(&stat.m_minute)
+                && (0..60).contains(&stat.m_second)
+                && stat.m_year >= 2000,
+            "Invalid modification time {}-{}-{} {}:{}:{}",
+            stat.m_year,
+            stat.m_month,
+            stat.m_day,
+            stat.m_hour,
+            stat.m_minute,
+            stat.m_second
+        );
+        let mut datetime = self.last_modified_pddatetime();
+        Ok(System::get().convert_datetime_to_epoch(&mut datetime)? as u32)
+    }
 }
 
 impl From<crankstart_sys::FileStat> for FileStat {
@@ -119,6 +153,20 @@ extern "C" fn list_files_callback(
     }
 }
 
+/// Internal function that gets passed to the C `listfiles()` call.
+///
+/// Used in [FileSystem::for_each_file], with `userdata` pointing at the caller's closure.
+extern "C" fn for_each_file_callback<F: FnMut(&str)>(
+    filename: *const crankstart_sys::ctypes::c_char,
+    userdata: *mut core::ffi::c_void,
+) {
+    unsafe {
+        let path = CStr::from_ptr(filename).to_string_lossy();
+        let f = &mut *(userdata as *mut F);
+        f(&path);
+    }
+}
+
 
 bitflags! {
     /// File handle flags to set when opening a file
@@ -221,6 +269,43 @@ impl FileSystem {
         Ok(*files)
     }
 
+    /// Like [FileSystem::listfiles], but sorted: directories first, then files, each group
+    /// ordered by name ignoring ASCII case.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn list_files_sorted(&self, path: &str, show_hidden: bool) -> Result<Vec<String>, Error> {
+        let mut files = self.listfiles(path, show_hidden)?;
+        files.sort_by(|a, b| {
+            b.ends_with('/')
+                .cmp(&a.ends_with('/'))
+                .then_with(|| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase()))
+                .then_with(|| a.cmp(b))
+        });
+        Ok(files)
+    }
+
+    /// Calls `f` with the name of every file at `path`, as the filesystem enumerates them,
+    /// without collecting them into a [Vec] first. Names follow the same rules as
+    /// [FileSystem::listfiles].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn for_each_file<F: FnMut(&str)>(
+        &self,
+        path: &str,
+        show_hidden: bool,
+        mut f: F,
+    ) -> Result<(), Error> {
+        let c_path = CString::new(path).map_err(Error::msg)?;
+        let result = pd_func_caller!(
+            (*self.0).listfiles,
+            c_path.as_ptr(),
+            Some(for_each_file_callback::<F>),
+            &mut f as *mut F as *mut core::ffi::c_void,
+            if show_hidden { 1 } else { 0 }
+        )?;
+        ensure_filesystem_success(result, "listfiles")
+    }
+
     /// Get information on a file, including whether it is a directory, the size (in bytes), and its last modified time.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-file.stat)
@@ -232,6 +317,41 @@ impl FileSystem {
         Ok(file_stat.into())
     }
 
+    /// Returns `true` if a file or directory exists at `path`.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn exists(&self, path: &str) -> bool {
+        self.stat(path).is_ok()
+    }
+
+    /// Returns whether `path` is a directory. A path that doesn't exist is not a directory,
+    /// but other failures, such as a path the filesystem can't read, are returned as errors.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn is_dir(&self, path: &str) -> Result<bool, Error> {
+        match self.stat(path) {
+            Ok(stat) => Ok(stat.is_dir()),
+            Err(_) if self.is_missing(path) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Whether `path` is absent from its parent directory's listing, to tell a path that
+    /// doesn't exist apart from one [FileSystem::stat()] failed on for another reason.
+    fn is_missing(&self, path: &str) -> bool {
+        let path = path.trim_end_matches('/');
+        let (parent, name) = match path.rfind('/') {
+            Some(index) => (&path[..index], &path[index + 1..]),
+            None => ("", path),
+        };
+        match self.listfiles(parent, true) {
+            Ok(entries) => !entries
+                .iter()
+                .any(|entry| entry.trim_end_matches('/') == name),
+            Err(_) => parent.is_empty() || self.is_missing(parent),
+        }
+    }
+
     /// Creates the given path in the Data/<gameid> folder. It does not create intermediate folders.
     /// 
     /// Returns nothing on success.
@@ -257,6 +377,109 @@ impl FileSystem {
         Ok(())
     }
 
+    /// Creates the given path in the Data/<gameid> folder, including any missing intermediate folders.
+    ///
+    /// Folders that already exist are left alone, so this is safe to call repeatedly.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn mkdir_all(&self, path: &str) -> Result<(), Error> {
+        let mut partial = String::with_capacity(path.len());
+        for component in path.split('/').filter(|component| !component.is_empty()) {
+            if !partial.is_empty() {
+                partial.push('/');
+            }
+            partial.push_str(component);
+            match self.stat(&partial) {
+                Ok(stat) => ensure!(stat.is_dir(), "{} exists and is not a directory", partial),
+                Err(_) => self.mkdir(&partial)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the total size in bytes of every file under the directory at `path`,
+    /// including hidden files and files in subfolders. Directories themselves count as zero.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn dir_size(&self, path: &str) -> Result<u64, Error> {
+        let mut total = 0;
+        self.visit_files(path, &mut |file| {
+            total += self.stat(file)?.size() as u64;
+            Ok(())
+        })?;
+        Ok(total)
+    }
+
+    /// Returns the number of files under the directory at `path`, including hidden files
+    /// and files in subfolders but not the subfolders themselves.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn file_count(&self, path: &str) -> Result<usize, Error> {
+        let mut count = 0;
+        self.visit_files(path, &mut |_| {
+            count += 1;
+            Ok(())
+        })?;
+        Ok(count)
+    }
+
+    /// Returns the path of every file under the directory at `path`, recursing into
+    /// subfolders. Paths are relative to the same place as `path` and include it, so they
+    /// can be passed straight to [FileSystem::open()]. Directories themselves aren't listed.
+    ///
+    /// Gives up with an error on folders nested more than [FileSystem::MAX_WALK_DEPTH] deep.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn walk(&self, path: &str, show_hidden: bool) -> Result<Vec<String>, Error> {
+        let mut files = Vec::new();
+        self.visit_files_with(path, show_hidden, 0, &mut |file| {
+            files.push(String::from(file));
+            Ok(())
+        })?;
+        Ok(files)
+    }
+
+    /// How many levels of subfolders [FileSystem::walk()] and the other recursive helpers will
+    /// descend into before giving up.
+    pub const MAX_WALK_DEPTH: usize = 32;
+
+    /// Calls `f` with the full path of every file under `dir`, recursing into subfolders.
+    fn visit_files(
+        &self,
+        dir: &str,
+        f: &mut dyn FnMut(&str) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        self.visit_files_with(dir, true, 0, f)
+    }
+
+    fn visit_files_with(
+        &self,
+        dir: &str,
+        show_hidden: bool,
+        depth: usize,
+        f: &mut dyn FnMut(&str) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        ensure!(
+            depth <= Self::MAX_WALK_DEPTH,
+            "{} is nested more than {} folders deep",
+            dir,
+            Self::MAX_WALK_DEPTH
+        );
+        for name in self.listfiles(dir, show_hidden)? {
+            let path = if dir.is_empty() || dir.ends_with('/') {
+                format!("{}{}", dir, name)
+            } else {
+                format!("{}/{}", dir, name)
+            };
+            if path.ends_with('/') {
+                self.visit_files_with(&path, show_hidden, depth + 1, f)?;
+            } else {
+                f(&path)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Renames the file at `from_path` to `to_path`. It will overwrite the file at `to_path` without confirmation.
     /// It does not create intermediate folders. Returns nothing on success.
     /// 
@@ -292,15 +515,162 @@ impl FileSystem {
         Ok(File(raw_file))
     }
 
+    /// Open the file at `path` and read it completely into a [Vec] of bytes.
+    ///
+    /// The data directory is checked first, then the game's pdx directory.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn read_file(&self, path: &str) -> Result<Vec<u8>, Error> {
+        let stat = self.stat(path)?;
+        let mut buffer = alloc::vec![0; stat.size() as usize];
+        let sd_file = self.open(path, OpenOptions::ReadDataAndPDX)?;
+        sd_file.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
     /// Open the file at `path` and read it completely into a Rust [String].
     /// 
     /// This is a convenience function and not from the original Playdate C API
     pub fn read_file_as_string(&self, path: &str) -> Result<String, Error> {
-        let stat = self.stat(path)?;
+        String::from_utf8(self.read_file(path)?).map_err(Error::msg)
+    }
+
+    /// Replace the contents of the file at `path` in the data directory with `data`,
+    /// creating the file if it doesn't exist.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn write_file(&self, path: &str, data: &[u8]) -> Result<(), Error> {
+        self.write_with_options(path, data, OpenOptions::Write)
+    }
+
+    /// Add `data` to the end of the file at `path` in the data directory, creating the file
+    /// if it doesn't exist.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn append_file(&self, path: &str, data: &[u8]) -> Result<(), Error> {
+        self.write_with_options(path, data, OpenOptions::Append)
+    }
+
+    /// Opens `path` with `options`, writes all of `data`, and flushes before closing.
+    fn write_with_options(
+        &self,
+        path: &str,
+        data: &[u8],
+        options: OpenOptions,
+    ) -> Result<(), Error> {
+        let file = self.open(path, options)?;
+        file.write_all(data)?;
+        file.flush()
+    }
+
+    /// Replace the contents of the file at `path` with `data` without ever leaving a
+    /// half-written file behind.
+    ///
+    /// The data is written to a temporary file next to `path` first, then renamed over it,
+    /// so a crash or power loss mid-save leaves either the old or the new contents in place.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn write_file_atomic(&self, path: &str, data: &[u8]) -> Result<(), Error> {
+        let tmp_path = format!("{}.tmp", path);
+        self.write_file(&tmp_path, data)?;
+        self.rename(&tmp_path, path)
+    }
+}
+
+/// A set of numbered save slots, stored as one file per slot inside a base directory
+/// in the game's data folder.
+///
+/// Slots are written with [FileSystem::write_file_atomic()], so an interrupted save never
+/// corrupts an existing slot.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # extern crate alloc;
+/// # use {anyhow::Error, crankstart::{file::SaveSlots, log_to_console}};
+/// # fn main() -> Result<(), Error> {
+/// # let save_data = [0u8; 16];
+/// let slots = SaveSlots::new("saves");
+/// slots.write(1, &save_data)?;
+/// for slot in slots.list()? {
+///     log_to_console!("slot {} is in use", slot);
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SaveSlots {
+    base_dir: String,
+}
+
+impl SaveSlots {
+    const PREFIX: &'static str = "slot";
+    const EXTENSION: &'static str = ".sav";
+
+    /// Create a `SaveSlots` storing its slots inside `base_dir`.
+    ///
+    /// Nothing is created on disk until the first [SaveSlots::write()].
+    pub fn new<S: AsRef<str>>(base_dir: S) -> Self {
+        Self {
+            base_dir: String::from(base_dir.as_ref().trim_end_matches('/')),
+        }
+    }
+
+    /// The path of the file backing `slot`.
+    pub fn path(&self, slot: u8) -> String {
+        format!(
+            "{}/{}{}{}",
+            self.base_dir,
+            Self::PREFIX,
+            slot,
+            Self::EXTENSION
+        )
+    }
+
+    /// Return `true` if `slot` currently holds a save.
+    pub fn exists(&self, slot: u8) -> bool {
+        FileSystem::get().exists(&self.path(slot))
+    }
+
+    /// Read the complete contents of `slot`.
+    pub fn read(&self, slot: u8) -> Result<Vec<u8>, Error> {
+        let file_sys = FileSystem::get();
+        let path = self.path(slot);
+        let stat = file_sys.stat(&path)?;
         let mut buffer = alloc::vec![0; stat.size() as usize];
-        let sd_file = self.open(path, OpenOptions::ReadDataAndPDX)?;
-        sd_file.read(&mut buffer)?;
-        String::from_utf8(buffer).map_err(Error::msg)
+        let file = file_sys.open(&path, OpenOptions::ReadData)?;
+        file.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Replace the contents of `slot` with `data`, creating the base directory if needed.
+    pub fn write(&self, slot: u8, data: &[u8]) -> Result<(), Error> {
+        let file_sys = FileSystem::get();
+        file_sys.mkdir_all(&self.base_dir)?;
+        file_sys.write_file_atomic(&self.path(slot), data)
+    }
+
+    /// Delete the save in `slot`.
+    pub fn delete(&self, slot: u8) -> Result<(), Error> {
+        FileSystem::get().unlink(&self.path(slot), false)
+    }
+
+    /// Return the numbers of every occupied slot, in ascending order.
+    pub fn list(&self) -> Result<Vec<u8>, Error> {
+        let file_sys = FileSystem::get();
+        if file_sys.stat(&self.base_dir).is_err() {
+            return Ok(Vec::new());
+        }
+        let mut slots = file_sys
+            .listfiles(&self.base_dir, false)?
+            .iter()
+            .filter_map(|name| {
+                name.strip_prefix(Self::PREFIX)?
+                    .strip_suffix(Self::EXTENSION)?
+                    .parse::<u8>()
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        slots.sort_unstable();
+        Ok(slots)
     }
 }
 
@@ -318,6 +688,18 @@ pub enum Whence {
     End = crankstart_sys::SEEK_END as i32,
 }
 
+/// A fixed-size record that can be decoded from `N` bytes, for use with [File::read_records()].
+pub trait FromBytes<const N: usize>: Sized {
+    /// Decode a record from its `N` byte representation.
+    fn from_bytes(bytes: &[u8; N]) -> Self;
+}
+
+impl<const N: usize> FromBytes<N> for [u8; N] {
+    fn from_bytes(bytes: &[u8; N]) -> Self {
+        *bytes
+    }
+}
+
 /// An open file handle on the Playdate console.
 /// 
 /// Calls [close()](https://sdk.play.date/inside-playdate-with-c/#f-file.close) on the file handle when dropped.
@@ -326,6 +708,9 @@ pub struct File(*mut SDFile);
 
 impl File {
     /// Reads up to `len` bytes from the file into the buffer buf. Returns the number of bytes read.
+    ///
+    /// A single read can return fewer bytes than `buf.len()` even before the end of the file;
+    /// use [File::read_exact()] to fill the whole buffer.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-file.read)
 
@@ -342,6 +727,67 @@ impl File {
         Ok(result as usize)
     }
 
+    /// Reads exactly `buf.len()` bytes from the file, calling [File::read()] as many times as needed.
+    ///
+    /// Returns an error if the end of the file is reached before `buf` is full.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn read_exact(&self, buf: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let count = self.read(&mut buf[filled..])?;
+            ensure!(
+                count > 0,
+                "Unexpected end of file after {} of {} bytes",
+                filled,
+                buf.len()
+            );
+            filled += count;
+        }
+        Ok(())
+    }
+
+    /// Reads `count` fixed-size records of `N` bytes each and decodes them with [FromBytes].
+    ///
+    /// All `count * N` bytes are read in a single [File::read_exact()], which keeps the number
+    /// of calls into the SDK down when bulk loading things like level data.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    /// ```no_run
+    /// # use {anyhow::Error, crankstart::file::{FileSystem, FromBytes, OpenOptions}};
+    /// # fn main() -> Result<(), Error> {
+    /// # let file = FileSystem::get().open("entities.dat", OpenOptions::ReadData)?;
+    /// # let entity_count = 4;
+    /// struct Entity { x: i32, y: i32 }
+    ///
+    /// impl FromBytes<8> for Entity {
+    ///     fn from_bytes(bytes: &[u8; 8]) -> Self {
+    ///         Entity {
+    ///             x: i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+    ///             y: i32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let entities: Vec<Entity> = file.read_records(entity_count)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn read_records<T: FromBytes<N>, const N: usize>(
+        &self,
+        count: usize,
+    ) -> Result<Vec<T>, Error> {
+        let mut buffer = alloc::vec![0; count * N];
+        self.read_exact(&mut buffer)?;
+        Ok(buffer
+            .chunks_exact(N)
+            .map(|chunk| {
+                let mut record = [0; N];
+                record.copy_from_slice(chunk);
+                T::from_bytes(&record)
+            })
+            .collect())
+    }
+
     /// Writes the buffer of bytes `buf` to the file. Returns the number of bytes written.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-file.write
@@ -358,6 +804,19 @@ impl File {
         Ok(result as usize)
     }
 
+    /// Writes all of `buf` to the file, calling [File::write()] as many times as needed.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn write_all(&self, buf: &[u8]) -> Result<(), Error> {
+        let mut written = 0;
+        while written < buf.len() {
+            let count = self.write(&buf[written..])?;
+            ensure!(count > 0, "File accepted no more data after {} bytes", written);
+            written += count;
+        }
+        Ok(())
+    }
+
     /// Flushes the output buffer of file immediately. Returns the number of bytes written.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-file.flush)
@@ -390,6 +849,51 @@ impl File {
         ensure_filesystem_success(result, "seek")?;
         Ok(())
     }
+
+    /// Like [File::seek()], but returns the new offset from the start of the file, so a
+    /// relative seek doesn't need a separate [File::tell()].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn seek_to(&self, pos: i32, whence: Whence) -> Result<i32, Error> {
+        self.seek(pos, whence)?;
+        self.tell()
+    }
+
+    /// Moves the read/write offset back to the start of the file.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn rewind(&self) -> Result<(), Error> {
+        self.seek(0, Whence::Set)
+    }
+
+    /// Returns the size of the file in bytes, by seeking to the end. The read/write offset
+    /// is put back where it was afterwards.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn size(&self) -> Result<u32, Error> {
+        let position = self.tell()?;
+        self.seek(0, Whence::End)?;
+        let size = self.tell();
+        self.seek(position, Whence::Set)?;
+        Ok(size? as u32)
+    }
+
+    /// Reads up to `buf.len()` bytes starting at `offset` from the start of the file, then
+    /// puts the read/write offset back where it was. Returns the number of bytes read.
+    ///
+    /// Useful for random access into archives without disturbing a sequential reader, at
+    /// the cost of an extra [File::tell()] and two [File::seek()]s per call.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, Error> {
+        let offset =
+            i32::try_from(offset).map_err(|_| anyhow!("Offset {} is too large", offset))?;
+        let position = self.tell()?;
+        self.seek(offset, Whence::Set)?;
+        let result = self.read(buf);
+        self.seek(position, Whence::Set)?;
+        result
+    }
 }
 
 impl Drop for File {
@@ -0,0 +1,225 @@
+use {
+    crate::{
+        file::{File, Whence},
+        log_to_console,
+    },
+    alloc::{string::String, vec::Vec},
+    anyhow::{ensure, Error},
+};
+
+/// A [File] with read and write buffers, so parsing a file a few bytes at a time doesn't
+/// cost a call into the SDK for every byte.
+///
+/// Reads fetch `capacity` bytes at a time and are served from the buffer; writes collect in
+/// a buffer of the same size and go out when it fills, on [BufFile::flush()], or on drop.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # extern crate alloc;
+/// # use {anyhow::Error, crankstart::{file::{BufFile, FileSystem, OpenOptions}, log_to_console}};
+/// # fn main() -> Result<(), Error> {
+/// let file = FileSystem::get().open("level.txt", OpenOptions::ReadDataAndPDX)?;
+/// let mut reader = BufFile::new(file);
+/// let mut line = String::new();
+/// while reader.read_line(&mut line)? > 0 {
+///     log_to_console!("{}", line.trim_end());
+///     line.clear();
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct BufFile {
+    file: File,
+    capacity: usize,
+    read_buf: Vec<u8>,
+    read_pos: usize,
+    write_buf: Vec<u8>,
+}
+
+impl BufFile {
+    /// The buffer size used by [BufFile::new()].
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Wrap `file` with buffers of [BufFile::DEFAULT_CAPACITY] bytes.
+    pub fn new(file: File) -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY, file)
+    }
+
+    /// Wrap `file` with buffers of `capacity` bytes.
+    pub fn with_capacity(capacity: usize, file: File) -> Self {
+        Self {
+            file,
+            capacity: capacity.max(1),
+            read_buf: Vec::new(),
+            read_pos: 0,
+            write_buf: Vec::new(),
+        }
+    }
+
+    /// The wrapped file. Reading or writing it directly skips past the buffers.
+    pub fn get_ref(&self) -> &File {
+        &self.file
+    }
+
+    /// The buffered bytes not yet returned by a read, fetching more from the file if the
+    /// buffer is empty. An empty slice means the end of the file.
+    pub fn fill_buf(&mut self) -> Result<&[u8], Error> {
+        if self.read_pos >= self.read_buf.len() {
+            self.flush()?;
+            self.read_buf.resize(self.capacity, 0);
+            let count = self.file.read(&mut self.read_buf)?;
+            self.read_buf.truncate(count);
+            self.read_pos = 0;
+        }
+        Ok(&self.read_buf[self.read_pos..])
+    }
+
+    /// Mark `amount` bytes from [BufFile::fill_buf()] as read.
+    pub fn consume(&mut self, amount: usize) {
+        self.read_pos = (self.read_pos + amount).min(self.read_buf.len());
+    }
+
+    /// Reads up to `buf.len()` bytes. Returns the number of bytes read, which is 0 only at
+    /// the end of the file.
+    pub fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let available = self.fill_buf()?;
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.consume(count);
+        Ok(count)
+    }
+
+    /// Reads exactly `buf.len()` bytes, returning an error if the file ends first.
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let count = self.read(&mut buf[filled..])?;
+            ensure!(
+                count > 0,
+                "Unexpected end of file after {} of {} bytes",
+                filled,
+                buf.len()
+            );
+            filled += count;
+        }
+        Ok(())
+    }
+
+    /// Reads bytes up to and including the next `'\n'` and appends them to `line`.
+    /// Returns the number of bytes read, which is 0 only at the end of the file.
+    ///
+    /// The line must be valid UTF-8; `line` is left unchanged if it isn't.
+    pub fn read_line(&mut self, line: &mut String) -> Result<usize, Error> {
+        let mut bytes = Vec::new();
+        loop {
+            let available = self.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+            match available.iter().position(|&byte| byte == b'\n') {
+                Some(index) => {
+                    bytes.extend_from_slice(&available[..=index]);
+                    self.consume(index + 1);
+                    break;
+                }
+                None => {
+                    let count = available.len();
+                    bytes.extend_from_slice(available);
+                    self.consume(count);
+                }
+            }
+        }
+        let text = core::str::from_utf8(&bytes).map_err(Error::msg)?;
+        line.push_str(text);
+        Ok(bytes.len())
+    }
+
+    /// An iterator over the remaining bytes of the file. It stops after the first error.
+    pub fn bytes(&mut self) -> Bytes<'_> {
+        Bytes {
+            file: self,
+            failed: false,
+        }
+    }
+
+    /// Buffers `buf` to be written, writing out the buffer whenever it fills.
+    pub fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        self.discard_read_buf()?;
+        if self.write_buf.len() + buf.len() > self.capacity {
+            self.flush_write_buf()?;
+        }
+        if buf.len() >= self.capacity {
+            self.file.write_all(buf)?;
+        } else {
+            self.write_buf.extend_from_slice(buf);
+        }
+        Ok(buf.len())
+    }
+
+    /// Buffers all of `buf` to be written.
+    pub fn write_all(&mut self, buf: &[u8]) -> Result<(), Error> {
+        self.write(buf).map(|_| ())
+    }
+
+    /// Writes out any buffered data and flushes the file.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        if !self.write_buf.is_empty() {
+            self.flush_write_buf()?;
+            self.file.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush_write_buf(&mut self) -> Result<(), Error> {
+        self.file.write_all(&self.write_buf)?;
+        self.write_buf.clear();
+        Ok(())
+    }
+
+    /// Drops buffered reads, moving the file's offset back over the bytes not yet returned.
+    fn discard_read_buf(&mut self) -> Result<(), Error> {
+        let unread = self.read_buf.len() - self.read_pos;
+        if unread > 0 {
+            self.file.seek(-(unread as i32), Whence::Cur)?;
+        }
+        self.read_buf.clear();
+        self.read_pos = 0;
+        Ok(())
+    }
+}
+
+impl Drop for BufFile {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            log_to_console!("Error flushing BufFile: {}", err);
+        }
+    }
+}
+
+/// An iterator over the bytes of a [BufFile], returned by [BufFile::bytes()].
+#[derive(Debug)]
+pub struct Bytes<'a> {
+    file: &'a mut BufFile,
+    failed: bool,
+}
+
+impl Iterator for Bytes<'_> {
+    type Item = Result<u8, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.file.fill_buf() {
+            Ok(available) => {
+                let byte = *available.first()?;
+                self.file.consume(1);
+                Some(Ok(byte))
+            }
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
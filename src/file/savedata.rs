@@ -0,0 +1,283 @@
+use {
+    crate::file::{File, FileSystem, OpenOptions},
+    alloc::{string::String, vec::Vec},
+    anyhow::{anyhow, ensure, Error},
+    core::{convert::TryInto, ops::Range},
+};
+
+const MAGIC: &[u8; 4] = b"CSAV";
+const HEADER_LEN: usize = 6;
+// Each entry is a tag (u16), a type (u8) and a payload length (u32), then the payload.
+const ENTRY_HEADER_LEN: usize = 7;
+
+const TYPE_U32: u8 = 0;
+const TYPE_I32: u8 = 1;
+const TYPE_F32: u8 = 2;
+const TYPE_BOOL: u8 = 3;
+const TYPE_STRING: u8 = 4;
+const TYPE_BYTES: u8 = 5;
+
+/// A typed value stored in a save written by [SaveWriter].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SaveValue {
+    U32(u32),
+    I32(i32),
+    F32(f32),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+/// Builds a compact, versioned save made of tagged, typed fields, to be read back with
+/// [SaveReader].
+///
+/// Every field records its length, so a reader can skip tags it doesn't know about; new
+/// versions of a game can add fields without breaking old saves, and old versions can
+/// still read new saves. All numbers are little-endian.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::file::{SaveReader, SaveWriter}};
+/// # fn main() -> Result<(), Error> {
+/// const LEVEL: u16 = 1;
+/// const NAME: u16 = 2;
+///
+/// let mut save = SaveWriter::new(1);
+/// save.write_u32(LEVEL, 12);
+/// save.write_string(NAME, "Crankles");
+/// save.save("save.dat")?;
+///
+/// let save = SaveReader::load("save.dat")?;
+/// let level = save.read_u32(LEVEL)?.unwrap_or(1);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SaveWriter {
+    buffer: Vec<u8>,
+}
+
+impl SaveWriter {
+    /// Start a save, tagged with the game's save format `version`.
+    pub fn new(version: u16) -> Self {
+        let mut buffer = Vec::with_capacity(64);
+        buffer.extend_from_slice(MAGIC);
+        buffer.extend_from_slice(&version.to_le_bytes());
+        Self { buffer }
+    }
+
+    fn write_entry(&mut self, tag: u16, kind: u8, payload: &[u8]) {
+        self.buffer.extend_from_slice(&tag.to_le_bytes());
+        self.buffer.push(kind);
+        self.buffer
+            .extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        self.buffer.extend_from_slice(payload);
+    }
+
+    pub fn write_u32(&mut self, tag: u16, value: u32) {
+        self.write_entry(tag, TYPE_U32, &value.to_le_bytes());
+    }
+
+    pub fn write_i32(&mut self, tag: u16, value: i32) {
+        self.write_entry(tag, TYPE_I32, &value.to_le_bytes());
+    }
+
+    pub fn write_f32(&mut self, tag: u16, value: f32) {
+        self.write_entry(tag, TYPE_F32, &value.to_le_bytes());
+    }
+
+    pub fn write_bool(&mut self, tag: u16, value: bool) {
+        self.write_entry(tag, TYPE_BOOL, &[value as u8]);
+    }
+
+    pub fn write_string(&mut self, tag: u16, value: &str) {
+        self.write_entry(tag, TYPE_STRING, value.as_bytes());
+    }
+
+    pub fn write_bytes(&mut self, tag: u16, value: &[u8]) {
+        self.write_entry(tag, TYPE_BYTES, value);
+    }
+
+    /// Write any [SaveValue].
+    pub fn write_value(&mut self, tag: u16, value: &SaveValue) {
+        match value {
+            SaveValue::U32(value) => self.write_u32(tag, *value),
+            SaveValue::I32(value) => self.write_i32(tag, *value),
+            SaveValue::F32(value) => self.write_f32(tag, *value),
+            SaveValue::Bool(value) => self.write_bool(tag, *value),
+            SaveValue::String(value) => self.write_string(tag, value),
+            SaveValue::Bytes(value) => self.write_bytes(tag, value),
+        }
+    }
+
+    /// The encoded save.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Write the encoded save to `file`.
+    pub fn write_to(&self, file: &File) -> Result<(), Error> {
+        file.write_all(&self.buffer)
+    }
+
+    /// Replace the file at `path` in the game's data folder with the encoded save, using
+    /// [FileSystem::write_file_atomic()].
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        FileSystem::get().write_file_atomic(path, &self.buffer)
+    }
+}
+
+/// Reads fields by tag from a save written by [SaveWriter], in any order.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct SaveReader {
+    data: Vec<u8>,
+    version: u16,
+    entries: Vec<(u16, u8, Range<usize>)>,
+}
+
+impl SaveReader {
+    /// Decode a save, checking its header and the framing of every field.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, Error> {
+        ensure!(
+            data.len() >= HEADER_LEN && &data[..4] == MAGIC,
+            "Not a save file"
+        );
+        let version = u16::from_le_bytes([data[4], data[5]]);
+        let mut entries = Vec::new();
+        let mut offset = HEADER_LEN;
+        while offset < data.len() {
+            ensure!(
+                data.len() - offset >= ENTRY_HEADER_LEN,
+                "Save field header at offset {} is truncated",
+                offset
+            );
+            let tag = u16::from_le_bytes([data[offset], data[offset + 1]]);
+            let kind = data[offset + 2];
+            let len = u32::from_le_bytes(data[offset + 3..offset + 7].try_into()?) as usize;
+            let start = offset + ENTRY_HEADER_LEN;
+            ensure!(
+                data.len() - start >= len,
+                "Save field {} at offset {} is truncated",
+                tag,
+                offset
+            );
+            entries.push((tag, kind, start..start + len));
+            offset = start + len;
+        }
+        Ok(Self {
+            data,
+            version,
+            entries,
+        })
+    }
+
+    /// Read a whole save from `file`, starting at its current position.
+    pub fn read_from(file: &File) -> Result<Self, Error> {
+        let mut data = Vec::new();
+        let mut chunk = [0; 256];
+        loop {
+            let count = file.read(&mut chunk)?;
+            if count == 0 {
+                break;
+            }
+            data.extend_from_slice(&chunk[..count]);
+        }
+        Self::from_bytes(data)
+    }
+
+    /// Read the save at `path` in the game's data folder.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        Self::read_from(&FileSystem::get().open(path, OpenOptions::ReadData)?)
+    }
+
+    /// The save format version passed to [SaveWriter::new()].
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns `true` if the save has a field with `tag`.
+    pub fn has_field(&self, tag: u16) -> bool {
+        self.entries
+            .iter()
+            .any(|(entry_tag, _, _)| *entry_tag == tag)
+    }
+
+    /// The field with `tag`, or `None` if there isn't one. If a tag was written more than
+    /// once, the last value wins.
+    pub fn read_field(&self, tag: u16) -> Result<Option<SaveValue>, Error> {
+        let (kind, range) = match self
+            .entries
+            .iter()
+            .rev()
+            .find(|(entry_tag, _, _)| *entry_tag == tag)
+        {
+            Some((_, kind, range)) => (*kind, range.clone()),
+            None => return Ok(None),
+        };
+        let payload = &self.data[range];
+        let value = match kind {
+            TYPE_U32 => SaveValue::U32(u32::from_le_bytes(payload.try_into()?)),
+            TYPE_I32 => SaveValue::I32(i32::from_le_bytes(payload.try_into()?)),
+            TYPE_F32 => SaveValue::F32(f32::from_le_bytes(payload.try_into()?)),
+            TYPE_BOOL => {
+                ensure!(payload.len() == 1, "Save field {} is not a bool", tag);
+                SaveValue::Bool(payload[0] != 0)
+            }
+            TYPE_STRING => SaveValue::String(
+                String::from_utf8(payload.to_vec()).map_err(|err| anyhow!("{}", err))?,
+            ),
+            TYPE_BYTES => SaveValue::Bytes(payload.to_vec()),
+            _ => return Err(anyhow!("Save field {} has unknown type {}", tag, kind)),
+        };
+        Ok(Some(value))
+    }
+
+    pub fn read_u32(&self, tag: u16) -> Result<Option<u32>, Error> {
+        match self.read_field(tag)? {
+            Some(SaveValue::U32(value)) => Ok(Some(value)),
+            Some(other) => Err(anyhow!("Save field {} is {:?}, not a u32", tag, other)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn read_i32(&self, tag: u16) -> Result<Option<i32>, Error> {
+        match self.read_field(tag)? {
+            Some(SaveValue::I32(value)) => Ok(Some(value)),
+            Some(other) => Err(anyhow!("Save field {} is {:?}, not an i32", tag, other)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn read_f32(&self, tag: u16) -> Result<Option<f32>, Error> {
+        match self.read_field(tag)? {
+            Some(SaveValue::F32(value)) => Ok(Some(value)),
+            Some(other) => Err(anyhow!("Save field {} is {:?}, not an f32", tag, other)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn read_bool(&self, tag: u16) -> Result<Option<bool>, Error> {
+        match self.read_field(tag)? {
+            Some(SaveValue::Bool(value)) => Ok(Some(value)),
+            Some(other) => Err(anyhow!("Save field {} is {:?}, not a bool", tag, other)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn read_string(&self, tag: u16) -> Result<Option<String>, Error> {
+        match self.read_field(tag)? {
+            Some(SaveValue::String(value)) => Ok(Some(value)),
+            Some(other) => Err(anyhow!("Save field {} is {:?}, not a string", tag, other)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn read_bytes(&self, tag: u16) -> Result<Option<Vec<u8>>, Error> {
+        match self.read_field(tag)? {
+            Some(SaveValue::Bytes(value)) => Ok(Some(value)),
+            Some(other) => Err(anyhow!("Save field {} is {:?}, not bytes", tag, other)),
+            None => Ok(None),
+        }
+    }
+}
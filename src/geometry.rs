@@ -1,3 +1,5 @@
+use crankstart_sys::{LCDRect, PDRect};
+
 pub type ScreenCoord = i32;
 pub type ScreenPoint = euclid::default::Point2D<ScreenCoord>;
 pub type ScreenVector = euclid::default::Vector2D<ScreenCoord>;
@@ -9,3 +11,32 @@ pub type GrPoint = euclid::default::Point2D<GrCoord>;
 pub type GrVector = euclid::default::Vector2D<GrCoord>;
 pub type GrRect = euclid::default::Rect<GrCoord>;
 pub type GrSize = euclid::default::Size2D<GrCoord>;
+
+/// Convert a [ScreenRect] to the SDK's [PDRect].
+///
+/// This is a convenience function and not from the original Playdate C API
+pub fn screen_rect_to_pd_rect(rect: ScreenRect) -> PDRect {
+    rect.to_f32().into()
+}
+
+/// Convert the SDK's [PDRect] to a [ScreenRect], rounding outwards to whole pixels so the
+/// result covers all of `rect`.
+///
+/// This is a convenience function and not from the original Playdate C API
+pub fn pd_rect_to_screen_rect(rect: PDRect) -> ScreenRect {
+    LCDRect::from(rect).into()
+}
+
+/// Convert a [ScreenRect] to the SDK's [LCDRect], whose right and bottom edges are exclusive.
+///
+/// This is a convenience function and not from the original Playdate C API
+pub fn screen_rect_to_lcd_rect(rect: ScreenRect) -> LCDRect {
+    rect.into()
+}
+
+/// Convert the SDK's [LCDRect] to a [ScreenRect].
+///
+/// This is a convenience function and not from the original Playdate C API
+pub fn lcd_rect_to_screen_rect(rect: LCDRect) -> ScreenRect {
+    rect.into()
+}
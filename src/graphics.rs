@@ -6,7 +6,7 @@ use {
     },
     alloc::{format, rc::Rc, vec::Vec},
     anyhow::{anyhow, ensure, Error},
-    core::{cell::RefCell, ops::RangeInclusive, ptr, slice},
+    core::{cell::RefCell, convert::TryFrom, ops::RangeInclusive, ptr, slice},
     crankstart_sys::{ctypes::c_int, LCDBitmapTable, LCDPattern},
     cstr_core::{CStr, CString},
     euclid::default::{Point2D, Vector2D},
@@ -18,6 +18,30 @@ pub use crankstart_sys::{
     PDRect, PDStringEncoding, LCD_COLUMNS, LCD_ROWS, LCD_ROWSIZE,
 };
 
+pub mod animation;
+pub use animation::{Animation, PlayMode};
+pub mod crankscroller;
+pub use crankscroller::CrankScroller;
+pub mod dither;
+pub mod fog;
+pub use fog::FogOverlay;
+pub mod nineslice;
+pub use nineslice::NineSlice;
+pub mod panel;
+pub use panel::Panel;
+pub mod parallax;
+pub use parallax::{Parallax, ParallaxLayer};
+pub mod polygon;
+pub use polygon::Polygon;
+pub mod screenshake;
+pub use screenshake::ScreenShake;
+pub mod textcursor;
+pub use textcursor::TextCursor;
+pub mod transition;
+pub use transition::Transition;
+pub mod video;
+pub use video::{VideoInfo, VideoPlayer};
+
 pub fn rect_make(x: f32, y: f32, width: f32, height: f32) -> PDRect {
     PDRect {
         x,
@@ -28,26 +52,96 @@ pub fn rect_make(x: f32, y: f32, width: f32, height: f32) -> PDRect {
 }
 
 /// A color to use for rendering to the screen
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LCDColor {
     /// A solid color pattern: black, white, clear, or XOR
     Solid(LCDSolidColor),
-    /// A custom color pattern created by [Bitmap::into_color()]
+    /// A custom color pattern, e.g. from [Graphics::pattern_from_bitmap()]
     Pattern(LCDPattern),
 }
 
-impl From<LCDColor> for usize {
-    fn from(color: LCDColor) -> Self {
+impl LCDColor {
+    /// An opaque pattern from its 8 rows of pixels, one bit per pixel, most significant bit
+    /// on the left, with 1 for white.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn from_pattern_rows(rows: [u8; 8]) -> Self {
+        Self::from_pattern_with_mask(rows, [0xff; 8])
+    }
+
+    /// A pattern from its 8 rows of pixels and 8 rows of mask, laid out as in
+    /// [LCDColor::from_pattern_rows()]. Pixels whose mask bit is 0 are transparent.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn from_pattern_with_mask(rows: [u8; 8], mask: [u8; 8]) -> Self {
+        let mut pattern = [0; 16];
+        pattern[..8].copy_from_slice(&rows);
+        pattern[8..].copy_from_slice(&mask);
+        LCDColor::Pattern(pattern)
+    }
+}
+
+/// Converts to the C `LCDColor` representation: the color itself for solid colors, or a
+/// pointer to the pattern for patterns.
+///
+/// A pattern's pointer points into `color`, so `color` must stay alive, and unmoved, for as
+/// long as the result is used. Keep the [LCDColor] in a local for the duration of the C call
+/// and convert a reference to it, e.g. `(&color).into()`.
+impl From<&LCDColor> for usize {
+    fn from(color: &LCDColor) -> Self {
         match color {
-            LCDColor::Solid(solid_color) => solid_color as usize,
-            LCDColor::Pattern(pattern) => {
-                let pattern_ptr = &pattern as *const u8;
-                pattern_ptr as usize
-            }
+            LCDColor::Solid(solid_color) => *solid_color as usize,
+            LCDColor::Pattern(pattern) => pattern.as_ptr() as usize,
         }
     }
 }
 
+/// An 8x8 pattern that owns its pixels, e.g. from [Bitmap::as_pattern()].
+///
+/// The first 8 bytes are the rows of the pattern, one bit per pixel with 1 for white, and
+/// the last 8 are the rows of its mask, with 1 for opaque. Convert it into an [LCDColor] to
+/// draw with it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Pattern(pub LCDPattern);
+
+impl Pattern {
+    /// Create a pattern from its 8 pixel rows followed by its 8 mask rows.
+    pub const fn new(bytes: LCDPattern) -> Self {
+        Self(bytes)
+    }
+
+    /// The pattern's 8 pixel rows followed by its 8 mask rows.
+    pub fn as_bytes(&self) -> &LCDPattern {
+        &self.0
+    }
+
+    /// An opaque ordered-dither gray, from 0 (black) to 255 (white).
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub const fn gray(level: u8) -> Self {
+        Self(dither::gray(level))
+    }
+
+    /// A gray with 25% of its pixels white.
+    pub const DITHER_25: Pattern = Pattern::gray(64);
+    /// A gray with 50% of its pixels white, in a checkerboard.
+    pub const DITHER_50: Pattern = Pattern::gray(128);
+    /// A gray with 75% of its pixels white.
+    pub const DITHER_75: Pattern = Pattern::gray(191);
+}
+
+impl From<Pattern> for LCDColor {
+    fn from(pattern: Pattern) -> Self {
+        LCDColor::Pattern(pattern.0)
+    }
+}
+
+impl From<LCDPattern> for Pattern {
+    fn from(bytes: LCDPattern) -> Self {
+        Self(bytes)
+    }
+}
+
 /// Information about a [Bitmap], returned by [Bitmap::get_data()]
 #[derive(Debug)]
 pub struct BitmapData {
@@ -59,6 +153,19 @@ pub struct BitmapData {
     pub hasmask: bool,
 }
 
+/// Returns the byte index and bit mask of `point` in a bitmap plane described by `data`.
+fn pixel_index(point: Point2D<i32>, data: &BitmapData) -> Result<(usize, u8), Error> {
+    ensure!(
+        point.x >= 0 && point.y >= 0 && point.x < data.width && point.y < data.height,
+        "Pixel {:?} is outside the {}x{} bitmap",
+        point,
+        data.width,
+        data.height
+    );
+    let index = point.y as usize * data.rowbytes as usize + point.x as usize / 8;
+    Ok((index, 0x80 >> (point.x % 8)))
+}
+
 #[derive(Debug)]
 pub struct BitmapInner {
     pub(crate) raw_bitmap: *mut crankstart_sys::LCDBitmap,
@@ -88,6 +195,82 @@ impl BitmapInner {
         })
     }
 
+    /// Calls `f` with the bitmap's pixel data and mask, if it has one, along with its
+    /// [BitmapData]. Both planes are one bit per pixel in MSB order with each row padded to
+    /// `rowbytes` bytes; a set mask bit is opaque.
+    pub(crate) fn with_pixel_planes<T>(
+        &self,
+        f: impl FnOnce(&mut [u8], Option<&mut [u8]>, &BitmapData) -> T,
+    ) -> Result<T, Error> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut rowbytes = 0;
+        let mut mask_ptr = ptr::null_mut();
+        let mut data_ptr = ptr::null_mut();
+        pd_func_caller!(
+            (*Graphics::get_ptr()).getBitmapData,
+            self.raw_bitmap,
+            &mut width,
+            &mut height,
+            &mut rowbytes,
+            &mut mask_ptr,
+            &mut data_ptr,
+        )?;
+        ensure!(!data_ptr.is_null(), "Bitmap has no pixel data");
+        let data = BitmapData {
+            width,
+            height,
+            rowbytes,
+            hasmask: !mask_ptr.is_null(),
+        };
+        let len = (rowbytes * height) as usize;
+        let pixels = unsafe { slice::from_raw_parts_mut(data_ptr, len) };
+        let mask = if mask_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { slice::from_raw_parts_mut(mask_ptr, len) })
+        };
+        Ok(f(pixels, mask, &data))
+    }
+
+    pub fn get_pixel(&self, point: Point2D<i32>) -> Result<LCDSolidColor, Error> {
+        self.with_pixel_planes(|pixels, mask, data| {
+            let (index, bit) = pixel_index(point, data)?;
+            if let Some(mask) = mask {
+                if mask[index] & bit == 0 {
+                    return Ok(LCDSolidColor::kColorClear);
+                }
+            }
+            Ok(if pixels[index] & bit != 0 {
+                LCDSolidColor::kColorWhite
+            } else {
+                LCDSolidColor::kColorBlack
+            })
+        })?
+    }
+
+    pub fn set_pixel(&self, point: Point2D<i32>, color: LCDSolidColor) -> Result<(), Error> {
+        self.with_pixel_planes(|pixels, mask, data| {
+            let (index, bit) = pixel_index(point, data)?;
+            match color {
+                LCDSolidColor::kColorBlack => pixels[index] &= !bit,
+                LCDSolidColor::kColorWhite => pixels[index] |= bit,
+                LCDSolidColor::kColorXOR => pixels[index] ^= bit,
+                LCDSolidColor::kColorClear => {
+                    let mask = mask.ok_or_else(|| {
+                        anyhow!("Can't set a clear pixel in a bitmap without a mask")
+                    })?;
+                    mask[index] &= !bit;
+                    return Ok(());
+                }
+            }
+            if let Some(mask) = mask {
+                mask[index] |= bit;
+            }
+            Ok(())
+        })?
+    }
+
     pub fn draw(&self, location: ScreenPoint, flip: LCDBitmapFlip) -> Result<(), Error> {
         pd_func_caller!(
             (*Graphics::get_ptr()).drawBitmap,
@@ -168,7 +351,7 @@ impl BitmapInner {
         pd_func_caller!(
             (*Graphics::get_ptr()).clearBitmap,
             self.raw_bitmap,
-            color.into()
+            (&color).into()
         )
     }
 
@@ -194,19 +377,8 @@ impl BitmapInner {
         todo!();
     }
 
-    pub fn into_color(&self, top_left: Point2D<i32>) -> Result<LCDColor, Error> {
-        let mut pattern = LCDPattern::default();
-        let pattern_ptr = pattern.as_mut_ptr();
-        let mut pattern_val = pattern_ptr as usize;
-        let graphics = Graphics::get();
-        pd_func_caller!(
-            (*graphics.0).setColorToPattern,
-            &mut pattern_val,
-            self.raw_bitmap,
-            top_left.x,
-            top_left.y
-        )?;
-        Ok(LCDColor::Pattern(pattern))
+    pub fn as_pattern(&self, top_left: Point2D<i32>) -> Result<Pattern, Error> {
+        Graphics::get().pattern_from_raw_bitmap(self.raw_bitmap, top_left)
     }
 
     pub fn load(&self, path: &str) -> Result<(), Error> {
@@ -227,6 +399,29 @@ impl BitmapInner {
         }
     }
 
+    pub fn get_mask(&self) -> Result<Option<Bitmap>, Error> {
+        let raw_mask = pd_func_caller!((*Graphics::get_ptr()).getBitmapMask, self.raw_bitmap)?;
+        if raw_mask.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Bitmap::from_raw_parts(raw_mask, true)))
+        }
+    }
+
+    pub fn set_mask(&self, mask: &Bitmap) -> Result<(), Error> {
+        let raw_mask = mask.inner.borrow().raw_bitmap;
+        let result = pd_func_caller!(
+            (*Graphics::get_ptr()).setBitmapMask,
+            self.raw_bitmap,
+            raw_mask
+        )?;
+        if result == 0 {
+            Err(anyhow!("Mask must be the same size as the bitmap"))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn check_mask_collision(
         &self,
         my_location: ScreenPoint,
@@ -292,13 +487,73 @@ impl Bitmap {
     /// the high bit of the first byte in data is the top left pixel of the image.
     /// 
     /// If the bitmap has a mask, a pointer to its data is returned in mask, else NULL is returned.
-    /// Rust implementation note: the mask is currenly discarded if returned
+    /// Rust implementation note: the pixel data and mask aren't returned here; use
+    /// [Bitmap::with_pixels()] to access them
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.getBitmapData)
     pub fn get_data(&self) -> Result<BitmapData, Error> {
         self.inner.borrow().get_data()
     }
 
+    /// Returns the bitmap's width and height.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_size(&self) -> Result<ScreenSize, Error> {
+        let data = self.get_data()?;
+        Ok(ScreenSize::new(data.width, data.height))
+    }
+
+    /// Calls `f` with the bitmap's pixel data, and its mask if it has one, to read or write
+    /// pixels in bulk. Returns whatever `f` returns.
+    ///
+    /// Both slices are [`rowbytes`][BitmapData::rowbytes] × [`height`][BitmapData::height]
+    /// bytes, with one bit per pixel in MSB order: the high bit of the first byte is the top
+    /// left pixel. Each row is padded to a multiple of 32 bits, so rows start every
+    /// `rowbytes` bytes rather than every `width / 8`. In the pixel data a set bit is white;
+    /// in the mask a set bit is opaque.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    /// ```no_run
+    /// # use {anyhow::Error, crankstart::graphics::Graphics};
+    /// # fn main() -> Result<(), Error> {
+    /// # let bitmap = Graphics::get().load_bitmap("images/player")?;
+    /// let data = bitmap.get_data()?;
+    /// bitmap.with_pixels(|pixels, _mask| {
+    ///     // fill the top row with black
+    ///     let rowbytes = data.rowbytes as usize;
+    ///     pixels[..rowbytes].fill(0);
+    /// })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn with_pixels<F, T>(&self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut [u8], Option<&mut [u8]>) -> T,
+    {
+        // The closure can modify the bitmap, so borrow mutably for safety.
+        self.inner
+            .borrow_mut()
+            .with_pixel_planes(|pixels, mask, _| f(pixels, mask))
+    }
+
+    /// Returns the color of the pixel at `point`: black, white, or clear if the bitmap has
+    /// a mask and the pixel is masked out.
+    ///
+    /// This reads the bitmap's data directly, so it's much cheaper than drawing.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_pixel(&self, point: Point2D<i32>) -> Result<LCDSolidColor, Error> {
+        self.inner.borrow().get_pixel(point)
+    }
+
+    /// Sets the pixel at `point` to `color`. Black and white pixels are made opaque if the
+    /// bitmap has a mask, XOR inverts the pixel, and clear masks the pixel out, which is an
+    /// error if the bitmap has no mask.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn set_pixel(&self, point: Point2D<i32>, color: LCDSolidColor) -> Result<(), Error> {
+        self.inner.borrow().set_pixel(point, color)
+    }
+
     /// Draws the bitmap with its upper-left corner at `location`, using the given [`flip` orientation][LCDBitmapFlip].
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.drawBitmap)
@@ -316,6 +571,39 @@ impl Bitmap {
         self.inner.borrow().draw_scaled(location, scale)
     }
 
+    /// Draws the bitmap scaled to `scale` and flipped by `flip`, with its upper-left corner
+    /// at `location` whichever way it's flipped.
+    ///
+    /// This negates the scale along each flipped axis, and moves the point passed to the SDK
+    /// to the far edge so the flipped bitmap still covers the same area as an unflipped one.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_scaled_flipped(
+        &self,
+        location: ScreenPoint,
+        scale: Vector2D<f32>,
+        flip: LCDBitmapFlip,
+    ) -> Result<(), Error> {
+        let (flip_x, flip_y) = match flip {
+            LCDBitmapFlip::kBitmapUnflipped => (false, false),
+            LCDBitmapFlip::kBitmapFlippedX => (true, false),
+            LCDBitmapFlip::kBitmapFlippedY => (false, true),
+            LCDBitmapFlip::kBitmapFlippedXY => (true, true),
+        };
+        let size = self.get_size()?;
+        let mut location = location;
+        let mut scale = scale.abs();
+        if flip_x {
+            location.x += (size.width as f32 * scale.x) as i32;
+            scale.x = -scale.x;
+        }
+        if flip_y {
+            location.y += (size.height as f32 * scale.y) as i32;
+            scale.y = -scale.y;
+        }
+        self.draw_scaled(location, scale)
+    }
+
     /// Draw the `Bitmap` to the given `location`, rotated `degrees` about the `center` point,
     /// scaled up or down in size by `scale`.  `center` is given by two numbers between 0.0 and
     /// 1.0, where (0, 0) is the top left and (0.5, 0.5) is the center point.
@@ -369,19 +657,47 @@ impl Bitmap {
         })
     }
 
-    /// Use a 8x8 pattern from the bitmap to create a [LCDColor].
+    /// Copy an 8x8 [Pattern] out of the bitmap, which can be turned into an [LCDColor].
     /// 
     /// `top_left` indicates the top left corner of the 8 x 8 pattern within the bitmap.
+    /// The pattern is copied, so it stays valid after the bitmap is changed or dropped.
+    ///
+    /// Equivalent to [Graphics::pattern_from_bitmap()].
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setColorToPattern)
+    pub fn as_pattern(&self, top_left: Point2D<i32>) -> Result<Pattern, Error> {
+        self.inner.borrow().as_pattern(top_left)
+    }
+
+    /// Use a 8x8 pattern from the bitmap to create a [LCDColor].
+    ///
+    /// `top_left` indicates the top left corner of the 8 x 8 pattern within the bitmap.
+    #[deprecated(note = "use `as_pattern` and convert the `Pattern` into an `LCDColor`")]
     pub fn into_color(&self, top_left: Point2D<i32>) -> Result<LCDColor, Error> {
-        self.inner.borrow().into_color(top_left)
+        Ok(self.as_pattern(top_left)?.into())
     }
 
     pub fn load(&self, path: &str) -> Result<(), Error> {
         self.inner.borrow().load(path)
     }
 
+    /// Gets the bitmap's mask layer, or `None` if it doesn't have one.
+    ///
+    /// The mask shares its data with this bitmap, so drawing into it changes which of this
+    /// bitmap's pixels are opaque. White pixels in the mask are opaque, black are transparent.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.getBitmapMask)
+    pub fn get_mask(&self) -> Result<Option<Bitmap>, Error> {
+        self.inner.borrow().get_mask()
+    }
+
+    /// Sets the bitmap's mask layer to `mask`, which must be the same size as the bitmap.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setBitmapMask)
+    pub fn set_mask(&self, mask: &Bitmap) -> Result<(), Error> {
+        self.inner.borrow().set_mask(mask)
+    }
+
     /// Check the collision between this bitmap and the `other` bitmap.
     /// 
     /// Returns `true` if:
@@ -408,6 +724,312 @@ impl Bitmap {
             rect,
         )
     }
+
+    /// Check the collision between this bitmap and the `other` bitmap, with each of them
+    /// rotated by some degrees about its center and scaled, as [Bitmap::draw_rotated()] draws
+    /// them with a center of (0.5, 0.5).
+    ///
+    /// The bitmaps are positioned by their centers rather than their upper-left corners,
+    /// since rotating changes a bitmap's size but not where its center is. Like
+    /// [Bitmap::check_mask_collision()], only the parts within `rect` are checked.
+    ///
+    /// This makes rotated copies of both bitmaps on every call, so for bitmaps that rotate
+    /// rarely it's cheaper to keep copies from [Bitmap::rotated()] and call
+    /// [Bitmap::check_mask_collision()] with them directly.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_mask_collision_transformed(
+        &self,
+        my_center: ScreenPoint,
+        my_degrees: f32,
+        my_scale: Vector2D<f32>,
+        other: &Bitmap,
+        other_center: ScreenPoint,
+        other_degrees: f32,
+        other_scale: Vector2D<f32>,
+        rect: ScreenRect,
+    ) -> Result<bool, Error> {
+        let mine = self.rotated(my_degrees, my_scale)?;
+        let theirs = other.rotated(other_degrees, other_scale)?;
+        let top_left = |bitmap: &Bitmap, center: ScreenPoint| -> Result<ScreenPoint, Error> {
+            let size = bitmap.get_size()?;
+            Ok(center - ScreenVector::new(size.width / 2, size.height / 2))
+        };
+        let my_location = top_left(&mine, my_center)?;
+        let other_location = top_left(&theirs, other_center)?;
+        mine.check_mask_collision(
+            my_location,
+            LCDBitmapFlip::kBitmapUnflipped,
+            theirs,
+            other_location,
+            LCDBitmapFlip::kBitmapUnflipped,
+            rect,
+        )
+    }
+
+    /// Return a copy of self with every opaque pixel recolored to `color`, which must be
+    /// black or white. Transparent pixels stay transparent.
+    ///
+    /// Handy for effects like flashing a sprite white when it takes damage; keep the result
+    /// around rather than re-tinting every frame.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn tinted(&self, color: LCDSolidColor) -> Result<Bitmap, Error> {
+        let mode = match color {
+            LCDSolidColor::kColorBlack => BitmapDrawMode::FillBlack,
+            LCDSolidColor::kColorWhite => BitmapDrawMode::FillWhite,
+            _ => return Err(anyhow!("Bitmaps can only be tinted black or white")),
+        };
+        let graphics = Graphics::get();
+        let tinted = graphics.new_bitmap(
+            self.get_size()?,
+            LCDColor::Solid(LCDSolidColor::kColorClear),
+        )?;
+        // The draw mode is part of the context state, so it's restored when the context is popped.
+        graphics.with_context(&tinted, || {
+            graphics.set_draw_mode(mode)?;
+            self.draw(ScreenPoint::zero(), LCDBitmapFlip::kBitmapUnflipped)
+        })?;
+        Ok(tinted)
+    }
+
+    /// Replace the connected region of pixels sharing the color of the pixel at `seed` with
+    /// `color`, which must be black or white. Only the four orthogonal neighbors count as
+    /// connected, and the fill never leaves the bitmap.
+    ///
+    /// Uses a scanline fill with an explicit work queue, so large regions won't overflow
+    /// the stack.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn flood_fill(&self, seed: Point2D<i32>, color: LCDSolidColor) -> Result<(), Error> {
+        let fill = match color {
+            LCDSolidColor::kColorBlack => false,
+            LCDSolidColor::kColorWhite => true,
+            _ => return Err(anyhow!("Bitmaps can only be flood filled black or white")),
+        };
+        self.inner.borrow().with_pixel_planes(|pixels, _, data| {
+            ensure!(
+                seed.x >= 0 && seed.y >= 0 && seed.x < data.width && seed.y < data.height,
+                "Flood fill seed {:?} is outside the {}x{} bitmap",
+                seed,
+                data.width,
+                data.height
+            );
+            let rowbytes = data.rowbytes as usize;
+            let get = |pixels: &[u8], x: i32, y: i32| {
+                pixels[y as usize * rowbytes + x as usize / 8] & (0x80 >> (x % 8)) != 0
+            };
+            let set = |pixels: &mut [u8], x: i32, y: i32| {
+                let byte = &mut pixels[y as usize * rowbytes + x as usize / 8];
+                if fill {
+                    *byte |= 0x80 >> (x % 8);
+                } else {
+                    *byte &= !(0x80 >> (x % 8));
+                }
+            };
+
+            let target = get(pixels, seed.x, seed.y);
+            if target == fill {
+                return Ok(());
+            }
+            let mut queue = Vec::new();
+            queue.push(seed);
+            while let Some(point) = queue.pop() {
+                if get(pixels, point.x, point.y) != target {
+                    continue;
+                }
+                let mut left = point.x;
+                while left > 0 && get(pixels, left - 1, point.y) == target {
+                    left -= 1;
+                }
+                let mut right = point.x;
+                while right < data.width - 1 && get(pixels, right + 1, point.y) == target {
+                    right += 1;
+                }
+                for x in left..=right {
+                    set(pixels, x, point.y);
+                }
+                // Queue one seed per run of matching pixels on the rows above and below.
+                for y in [point.y - 1, point.y + 1] {
+                    if y < 0 || y >= data.height {
+                        continue;
+                    }
+                    let mut in_run = false;
+                    for x in left..=right {
+                        let matches = get(pixels, x, y) == target;
+                        if matches && !in_run {
+                            queue.push(Point2D::new(x, y));
+                        }
+                        in_run = matches;
+                    }
+                }
+            }
+            Ok(())
+        })?
+    }
+
+    /// Return a new bitmap, `thickness` pixels larger than self on every side, containing
+    /// just an outline in `color` around the opaque pixels of self: every transparent pixel
+    /// within `thickness` of an opaque one. `color` must be black or white.
+    ///
+    /// Draw the outline at `thickness` pixels up and left of where self is drawn, or use
+    /// [Bitmap::outlined()] to get both in one bitmap.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn outline(&self, thickness: i32, color: LCDSolidColor) -> Result<Bitmap, Error> {
+        let fill = match color {
+            LCDSolidColor::kColorBlack => false,
+            LCDSolidColor::kColorWhite => true,
+            _ => return Err(anyhow!("Outlines can only be black or white")),
+        };
+        ensure!(thickness > 0, "Outline thickness must be positive, got {}", thickness);
+
+        let (width, height, opaque) = self.inner.borrow().with_pixel_planes(|_, mask, data| {
+            let rowbytes = data.rowbytes as usize;
+            let mut opaque = Vec::with_capacity((data.width * data.height) as usize);
+            for y in 0..data.height as usize {
+                for x in 0..data.width as usize {
+                    opaque.push(match &mask {
+                        Some(mask) => mask[y * rowbytes + x / 8] & (0x80 >> (x % 8)) != 0,
+                        None => true,
+                    });
+                }
+            }
+            (data.width, data.height, opaque)
+        })?;
+        let is_opaque = |x: i32, y: i32| {
+            x >= 0 && y >= 0 && x < width && y < height && opaque[(y * width + x) as usize]
+        };
+        // Offsets within a circle of radius `thickness`, so corners come out rounded.
+        let mut offsets = Vec::new();
+        for dy in -thickness..=thickness {
+            for dx in -thickness..=thickness {
+                if dx * dx + dy * dy <= thickness * thickness {
+                    offsets.push((dx, dy));
+                }
+            }
+        }
+
+        let size = ScreenSize::new(width + thickness * 2, height + thickness * 2);
+        let clear = LCDColor::Solid(LCDSolidColor::kColorClear);
+        let outline = Graphics::get().new_bitmap(size, clear)?;
+        outline.inner.borrow().with_pixel_planes(|pixels, mask, data| -> Result<(), Error> {
+            let mask = mask.ok_or_else(|| anyhow!("Outline bitmap was created without a mask"))?;
+            let rowbytes = data.rowbytes as usize;
+            for y in 0..size.height {
+                for x in 0..size.width {
+                    let (sx, sy) = (x - thickness, y - thickness);
+                    if is_opaque(sx, sy)
+                        || !offsets.iter().any(|(dx, dy)| is_opaque(sx + dx, sy + dy))
+                    {
+                        continue;
+                    }
+                    let index = y as usize * rowbytes + x as usize / 8;
+                    let bit = 0x80 >> (x % 8);
+                    mask[index] |= bit;
+                    if fill {
+                        pixels[index] |= bit;
+                    } else {
+                        pixels[index] &= !bit;
+                    }
+                }
+            }
+            Ok(())
+        })??;
+        Ok(outline)
+    }
+
+    /// Return a copy of self scaled up by a whole-number `factor`, with every pixel copied
+    /// into a `factor` x `factor` block, mask included.
+    ///
+    /// Unlike [Bitmap::draw_scaled()], this never blends or drops pixels, so pixel art stays
+    /// crisp, and the scaling is done once rather than on every draw.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn scaled_nn(&self, factor: u32) -> Result<Bitmap, Error> {
+        ensure!(factor >= 1, "Scale factor must be at least 1, got {}", factor);
+        let source = self.inner.borrow();
+        let data = source.get_data()?;
+        let scale = |length: i32| {
+            i32::try_from(factor)
+                .ok()
+                .and_then(|factor| length.checked_mul(factor))
+                .ok_or_else(|| anyhow!("Scaling {} pixels by {} is too large", length, factor))
+        };
+        let size = ScreenSize::new(scale(data.width)?, scale(data.height)?);
+        let factor = factor as usize;
+        // Start from clear so the copy has a mask if the source does.
+        let background = if data.hasmask {
+            LCDSolidColor::kColorClear
+        } else {
+            LCDSolidColor::kColorBlack
+        };
+        let scaled = Graphics::get().new_bitmap(size, LCDColor::Solid(background))?;
+
+        source.with_pixel_planes(|src_pixels, src_mask, src_data| {
+            scaled.inner.borrow().with_pixel_planes(|pixels, mut mask, data| {
+                let src_rowbytes = src_data.rowbytes as usize;
+                let rowbytes = data.rowbytes as usize;
+                let is_set = |plane: &[u8], x: usize, y: usize| {
+                    plane[y * src_rowbytes + x / 8] & (0x80 >> (x % 8)) != 0
+                };
+                for y in 0..data.height as usize {
+                    for x in 0..data.width as usize {
+                        let (sx, sy) = (x / factor, y / factor);
+                        let index = y * rowbytes + x / 8;
+                        let bit = 0x80 >> (x % 8);
+                        let copy = |plane: &mut [u8], set: bool| {
+                            if set {
+                                plane[index] |= bit;
+                            } else {
+                                plane[index] &= !bit;
+                            }
+                        };
+                        copy(pixels, is_set(src_pixels, sx, sy));
+                        if let (Some(mask), Some(src_mask)) = (mask.as_mut(), src_mask.as_ref()) {
+                            copy(mask, is_set(src_mask, sx, sy));
+                        }
+                    }
+                }
+            })
+        })??;
+        Ok(scaled)
+    }
+
+    /// Like [Bitmap::outline()], with self drawn on top of the outline.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn outlined(&self, thickness: i32, color: LCDSolidColor) -> Result<Bitmap, Error> {
+        let outlined = self.outline(thickness, color)?;
+        Graphics::get().with_context(&outlined, || {
+            self.draw(
+                ScreenPoint::new(thickness, thickness),
+                LCDBitmapFlip::kBitmapUnflipped,
+            )
+        })?;
+        Ok(outlined)
+    }
+
+    /// Return a copy of the part of self inside `rect`. Parts of `rect` outside the bitmap
+    /// are cut off, so the result may be smaller than `rect`; it's an error if nothing is left.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn crop(&self, rect: ScreenRect) -> Result<Bitmap, Error> {
+        let bounds = ScreenRect::from_size(self.get_size()?);
+        let rect = rect
+            .intersection(&bounds)
+            .ok_or_else(|| anyhow!("Crop rect {:?} is outside the bitmap", rect))?;
+        let graphics = Graphics::get();
+        let cropped = graphics.new_bitmap(rect.size, LCDColor::Solid(LCDSolidColor::kColorClear))?;
+        graphics.with_context(&cropped, || {
+            self.draw(
+                ScreenPoint::zero() - rect.origin.to_vector(),
+                LCDBitmapFlip::kBitmapUnflipped,
+            )
+        })?;
+        Ok(cropped)
+    }
 }
 
 type OptionalBitmap<'a> = Option<&'a mut Bitmap>;
@@ -420,12 +1042,17 @@ fn raw_bitmap(bitmap: OptionalBitmap<'_>) -> *mut crankstart_sys::LCDBitmap {
     }
 }
 
-pub struct Font(*mut crankstart_sys::LCDFont);
+/// A font, loaded from a file with [Graphics::load_font()] or from memory with
+/// [Graphics::make_font_from_data()].
+///
+/// Fonts made from memory keep their data alive alongside them, since the SDK reads from it
+/// for as long as the font is in use.
+pub struct Font(*mut crankstart_sys::LCDFont, Option<Vec<u8>>);
 
 impl Font {
     pub fn new(font: *mut crankstart_sys::LCDFont) -> Result<Self, Error> {
         anyhow::ensure!(!font.is_null(), "Null pointer passed to Font::new");
-        Ok(Self(font))
+        Ok(Self(font, None))
     }
 }
 
@@ -440,6 +1067,17 @@ impl Drop for Font {
     }
 }
 
+/// Information about a [BitmapTable], returned by [BitmapTable::get_info()]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitmapTableInfo {
+    /// The number of bitmaps in the table
+    pub count: i32,
+    /// The width of each bitmap, in pixels
+    pub cell_width: i32,
+    /// The height of each bitmap, in pixels
+    pub cell_height: i32,
+}
+
 #[derive(Debug)]
 struct BitmapTableInner {
     raw_bitmap_table: *mut LCDBitmapTable,
@@ -468,6 +1106,48 @@ impl BitmapTableInner {
         }
     }
 
+    fn raw_table_bitmap(&self, index: usize) -> Result<*mut crankstart_sys::LCDBitmap, Error> {
+        pd_func_caller!(
+            (*Graphics::get_ptr()).getTableBitmap,
+            self.raw_bitmap_table,
+            index as c_int
+        )
+    }
+
+    fn get_info(&self) -> Result<BitmapTableInfo, Error> {
+        // These bindings don't include getBitmapTableInfo, but getTableBitmap returns null
+        // past the end of the table, so search for the first missing index.
+        if self.raw_table_bitmap(0)?.is_null() {
+            return Ok(BitmapTableInfo {
+                count: 0,
+                cell_width: 0,
+                cell_height: 0,
+            });
+        }
+        let mut present = 0;
+        let mut missing = 1;
+        while !self.raw_table_bitmap(missing)?.is_null() {
+            present = missing;
+            missing *= 2;
+        }
+        while missing - present > 1 {
+            let middle = (present + missing) / 2;
+            if self.raw_table_bitmap(middle)?.is_null() {
+                missing = middle;
+            } else {
+                present = middle;
+            }
+        }
+        // The table owns its bitmaps, so don't free this one.
+        let first = Bitmap::from_raw_parts(self.raw_table_bitmap(0)?, false);
+        let data = first.get_data()?;
+        Ok(BitmapTableInfo {
+            count: missing as i32,
+            cell_width: data.width,
+            cell_height: data.height,
+        })
+    }
+
     fn load(&mut self, path: &str) -> Result<(), Error> {
         let c_path = CString::new(path).map_err(Error::msg)?;
         let mut out_err: *const crankstart_sys::ctypes::c_char = ptr::null_mut();
@@ -527,6 +1207,32 @@ impl BitmapTable {
     pub fn get_bitmap(&self, index: usize) -> Result<Bitmap, Error> {
         self.inner.borrow_mut().get_bitmap(index)
     }
+
+    /// Returns the number of bitmaps in the table and their size.
+    ///
+    /// The SDK's `getBitmapTableInfo` isn't available in these bindings, so the count is
+    /// found by searching for the end of the table with `getTableBitmap`, which takes a
+    /// handful of calls for large tables.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.getBitmapTableInfo)
+    pub fn get_info(&self) -> Result<BitmapTableInfo, Error> {
+        self.inner.borrow().get_info()
+    }
+
+    /// Returns the number of bitmaps in the table.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn len(&self) -> Result<usize, Error> {
+        Ok(self.get_info()?.count as usize)
+    }
+
+    /// Returns `true` if the table has no bitmaps. This only checks for a first bitmap, so
+    /// it's cheaper than [BitmapTable::len()].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn is_empty(&self) -> Result<bool, Error> {
+        Ok(self.inner.borrow().raw_table_bitmap(0)?.is_null())
+    }
 }
 
 #[repr(u32)]
@@ -578,7 +1284,52 @@ impl From<BitmapDrawMode> for LCDBitmapDrawMode {
     }
 }
 
+/// How [Graphics::draw_text_fit()] shortens text that is too wide for its rect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FitStrategy {
+    /// Cut the text off at the last character that fits
+    Truncate,
+    /// Cut the text off and end it with "…", keeping room for the ellipsis
+    Ellipsize,
+}
+
+/// Clears the drawing stencil when dropped. Returned by [Graphics::with_stencil()].
+///
+/// Holds on to the stencil bitmap so it stays alive for as long as it's in use.
+#[must_use = "the stencil is cleared as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct StencilGuard {
+    _stencil: Bitmap,
+}
+
+impl Drop for StencilGuard {
+    fn drop(&mut self) {
+        pd_func_caller_log!((*Graphics::get_ptr()).setStencilImage, ptr::null_mut(), 0);
+    }
+}
+
+/// How text that is too wide is broken into lines, for [Graphics::get_text_height_in_rect()].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextWrap {
+    /// Don't wrap; each line is cut off at the edge
+    Clip,
+    /// Break lines at whichever character reaches the edge
+    Character,
+    /// Break lines between words, breaking words that are too long on their own
+    Word,
+}
+
 static mut GRAPHICS: Graphics = Graphics(ptr::null_mut());
+static mut CRANK_INDICATOR_LAST_ANGLE: Option<f32> = None;
+// The clip rect last set through `Graphics`, and whether it was in screen coordinates, so
+// `with_clip` can put it back. The SDK has no getter for it.
+static mut CLIP_RECT: Option<(ScreenRect, bool)> = None;
+// The text tracking and leading last set through `Graphics`, as the SDK has no getters for
+// them.
+static mut TEXT_TRACKING: i32 = 0;
+static mut TEXT_LEADING: i32 = 0;
+// The draw offset last set through `Graphics`, for `with_offset`. The SDK has no getter.
+static mut DRAW_OFFSET: ScreenVector = ScreenVector::new(0, 0);
 
 #[derive(Clone, Debug)]
 pub struct Graphics(*const crankstart_sys::playdate_graphics);
@@ -622,6 +1373,66 @@ impl Graphics {
         pd_func_caller!((*self.0).popContext)
     }
 
+    /// Sets the stencil used for drawing. Only pixels where the stencil is white are drawn;
+    /// black pixels in the stencil mask drawing out.
+    ///
+    /// The stencil is tiled if it's smaller than the drawing area. The SDK keeps a pointer
+    /// to the bitmap rather than copying it, so keep `bitmap` alive until
+    /// [Graphics::clear_stencil()] is called, or use [Graphics::with_stencil()] which does that
+    /// for you.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setStencil)
+    pub fn set_stencil(&self, bitmap: &Bitmap) -> Result<(), Error> {
+        self.set_stencil_tiled(bitmap, true)
+    }
+
+    /// Sets the stencil used for drawing, as in [Graphics::set_stencil()]. If `tile` is false
+    /// the stencil is not repeated, and everything outside of it is drawn normally.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setStencilImage)
+    pub fn set_stencil_tiled(&self, bitmap: &Bitmap, tile: bool) -> Result<(), Error> {
+        pd_func_caller!(
+            (*self.0).setStencilImage,
+            bitmap.inner.borrow().raw_bitmap,
+            tile as c_int
+        )
+    }
+
+    /// Removes the stencil set with [Graphics::set_stencil()] so drawing is no longer masked.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setStencilImage)
+    pub fn clear_stencil(&self) -> Result<(), Error> {
+        pd_func_caller!((*self.0).setStencilImage, ptr::null_mut(), 0)
+    }
+
+    /// Sets the stencil used for drawing, as in [Graphics::set_stencil_tiled()], and returns
+    /// a guard that clears it again when dropped.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    /// ```no_run
+    /// # use {
+    /// #     anyhow::Error,
+    /// #     crankstart::graphics::{Graphics, LCDColor, LCDSolidColor},
+    /// #     euclid::default::{Point2D, Rect, Size2D},
+    /// # };
+    /// # fn main() -> Result<(), Error> {
+    /// # let graphics = Graphics::get();
+    /// # let dither_bitmap = graphics.load_bitmap("images/dither")?;
+    /// # let rect = Rect::new(Point2D::new(0, 0), Size2D::new(32, 32));
+    /// {
+    ///     let _stencil = graphics.with_stencil(&dither_bitmap, true)?;
+    ///     graphics.fill_rect(rect, LCDColor::Solid(LCDSolidColor::kColorBlack))?;
+    /// }
+    /// // drawing here isn't masked
+    /// # Ok(()) }
+    /// ```
+    pub fn with_stencil(&self, bitmap: &Bitmap, tile: bool) -> Result<StencilGuard, Error> {
+        self.set_stencil_tiled(bitmap, tile)?;
+        Ok(StencilGuard {
+            _stencil: bitmap.clone(),
+        })
+    }
+
     /// Returns the current display frame buffer.
     /// 
     /// Rows are 32-bit aligned, so the row stride is 52 bytes, with the extra 2 bytes per row ignored.
@@ -671,6 +1482,51 @@ impl Graphics {
         Ok(Bitmap::from_raw_parts(raw_bitmap, true))
     }
 
+    /// Returns a copy of the part of the working frame buffer inside `rect`, for minimaps
+    /// and effects that only need part of the screen. Parts of `rect` off the screen are
+    /// cut off, as in [Bitmap::crop()].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn capture_region(&self, rect: ScreenRect) -> Result<Bitmap, Error> {
+        self.get_framebuffer_bitmap()?.crop(rect)
+    }
+
+    /// Captures the working frame buffer so it can be drawn back later with
+    /// [Graphics::restore()], e.g. for screen transitions. This is
+    /// [Graphics::get_framebuffer_bitmap()] under a name that says what it's for.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn snapshot(&self) -> Result<Bitmap, Error> {
+        self.get_framebuffer_bitmap()
+    }
+
+    /// Draws a [Graphics::snapshot()] back over the whole screen.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn restore(&self, snapshot: &Bitmap) -> Result<(), Error> {
+        snapshot.draw(ScreenPoint::zero(), LCDBitmapFlip::kBitmapUnflipped)
+    }
+
+    /// Draws a dithered blend of two full-screen bitmaps, such as [Graphics::snapshot()]s:
+    /// `from` when `t` is 0, `to` when `t` is 1, and an ordered dither of the two in between.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn crossfade(&self, from: &Bitmap, to: &Bitmap, t: f32) -> Result<(), Error> {
+        let coverage = (t.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+        if coverage < 255 {
+            self.restore(from)?;
+        }
+        if coverage > 0 {
+            let stencil = self.new_bitmap(
+                ScreenSize::new(8, 8),
+                LCDColor::Pattern(dither::gray(coverage)),
+            )?;
+            let _guard = self.with_stencil(&stencil, true)?;
+            self.restore(to)?;
+        }
+        Ok(())
+    }
+
     /// Returns a bitmap containing the contents of the display buffer.
     /// 
     /// This is the active display buffer bitmap, not a copy.
@@ -731,7 +1587,95 @@ impl Graphics {
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setDrawOffset)
     pub fn set_draw_offset(&self, offset: ScreenVector) -> Result<(), Error> {
-        pd_func_caller!((*self.0).setDrawOffset, offset.x, offset.y)
+        pd_func_caller!((*self.0).setDrawOffset, offset.x, offset.y)?;
+        unsafe { DRAW_OFFSET = offset };
+        Ok(())
+    }
+
+    /// Gets the draw offset last set with [Graphics::set_draw_offset()].
+    ///
+    /// The C API has no getter for this, so an offset set directly through the SDK isn't seen.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_draw_offset(&self) -> ScreenVector {
+        unsafe { DRAW_OFFSET }
+    }
+
+    /// Runs `f` with `offset` added to the current draw offset, then puts the previous
+    /// offset back. Calls can be nested, e.g. a camera offset around a per-object offset.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn with_offset<F, T>(&self, offset: ScreenVector, f: F) -> Result<T, Error>
+    where
+        F: FnOnce() -> Result<T, Error>,
+    {
+        let previous = self.get_draw_offset();
+        self.set_draw_offset(previous + offset)?;
+        let res = f();
+        self.set_draw_offset(previous)?;
+        res
+    }
+
+    /// Sets the current clip rect, using world coordinates: the rect is shifted by the
+    /// current [draw offset][Graphics::set_draw_offset()]. Nothing is drawn outside of it.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setClipRect)
+    pub fn set_clip_rect(&self, rect: ScreenRect) -> Result<(), Error> {
+        pd_func_caller!(
+            (*self.0).setClipRect,
+            rect.origin.x,
+            rect.origin.y,
+            rect.size.width,
+            rect.size.height
+        )?;
+        unsafe { CLIP_RECT = Some((rect, false)) };
+        Ok(())
+    }
+
+    /// Sets the current clip rect in screen coordinates, ignoring the draw offset.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setScreenClipRect)
+    pub fn set_screen_clip_rect(&self, rect: ScreenRect) -> Result<(), Error> {
+        pd_func_caller!(
+            (*self.0).setScreenClipRect,
+            rect.origin.x,
+            rect.origin.y,
+            rect.size.width,
+            rect.size.height
+        )?;
+        unsafe { CLIP_RECT = Some((rect, true)) };
+        Ok(())
+    }
+
+    /// Clears the current clip rect so drawing can cover the whole screen again.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.clearClipRect)
+    pub fn clear_clip_rect(&self) -> Result<(), Error> {
+        pd_func_caller!((*self.0).clearClipRect)?;
+        unsafe { CLIP_RECT = None };
+        Ok(())
+    }
+
+    /// Runs `f` with the clip rect set to `rect`, as in [Graphics::set_clip_rect()], then
+    /// puts back the clip rect that was set before.
+    ///
+    /// Only clip rects set through [Graphics] can be restored; one set directly through the
+    /// SDK is cleared instead.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn with_clip<F, T>(&self, rect: ScreenRect, f: F) -> Result<T, Error>
+    where
+        F: FnOnce() -> Result<T, Error>,
+    {
+        let previous = unsafe { CLIP_RECT };
+        self.set_clip_rect(rect)?;
+        let res = f();
+        match previous {
+            Some((rect, true)) => self.set_screen_clip_rect(rect)?,
+            Some((rect, false)) => self.set_clip_rect(rect)?,
+            None => self.clear_clip_rect()?,
+        }
+        res
     }
 
     /// Allocates and returns a new [Bitmap] of [`size`][ScreenSize] dimensions filled with `bg_color`.
@@ -742,7 +1686,7 @@ impl Graphics {
             (*self.0).newBitmap,
             size.width,
             size.height,
-            bg_color.into()
+            (&bg_color).into()
         )?;
         anyhow::ensure!(
             !raw_bitmap.is_null(),
@@ -773,6 +1717,46 @@ impl Graphics {
         }
     }
 
+    /// Creates a pattern [LCDColor] from the 8 x 8 block of `bitmap` whose top left corner is
+    /// at `top_left`.
+    ///
+    /// The pattern is copied out of the bitmap, so the returned color stays valid after
+    /// `bitmap` is changed or dropped.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setColorToPattern)
+    pub fn pattern_from_bitmap(
+        &self,
+        bitmap: &Bitmap,
+        top_left: Point2D<i32>,
+    ) -> Result<LCDColor, Error> {
+        self.pattern_from_raw_bitmap(bitmap.inner.borrow().raw_bitmap, top_left)
+            .map(LCDColor::from)
+    }
+
+    fn pattern_from_raw_bitmap(
+        &self,
+        raw_bitmap: *mut crankstart_sys::LCDBitmap,
+        top_left: Point2D<i32>,
+    ) -> Result<Pattern, Error> {
+        let mut pattern = LCDPattern::default();
+        let pattern_ptr = pattern.as_mut_ptr();
+        let mut color = pattern_ptr as usize;
+        pd_func_caller!(
+            (*self.0).setColorToPattern,
+            &mut color,
+            raw_bitmap,
+            top_left.x,
+            top_left.y
+        )?;
+        // The SDK may fill our buffer or point the color at its own storage instead; either
+        // way, copy the pattern out so the returned color doesn't borrow anything.
+        if color != pattern_ptr as usize {
+            ensure!(color != 0, "setColorToPattern returned a null pattern");
+            pattern = unsafe { *(color as *const LCDPattern) };
+        }
+        Ok(Pattern(pattern))
+    }
+
     /// Allocates and returns a new [BitmapTable] that can hold `count` [Bitmap]s of size `size`.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.newBitmapTable)
@@ -810,11 +1794,21 @@ impl Graphics {
         }
     }
 
+    /// Opens the `.pdv` video at `path` as a [VideoPlayer].
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-video.loadVideo)
+    pub fn load_video(&self, path: &str) -> Result<VideoPlayer, Error> {
+        let c_path = CString::new(path).map_err(Error::msg)?;
+        let raw_player = pd_func_caller!((*(*self.0).video).loadVideo, c_path.as_ptr())?;
+        ensure!(!raw_player.is_null(), "load_video failed to open {}", path);
+        Ok(VideoPlayer::new(raw_player))
+    }
+
     /// Clears the entire display, filling it with [`color`][LCDColor].
     ///
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.clear)
     pub fn clear(&self, color: LCDColor) -> Result<(), Error> {
-        pd_func_caller!((*self.0).clear, color.into())
+        pd_func_caller!((*self.0).clear, (&color).into())
     }
 
     /// Draws a line from `p1` to `p2` with a stroke width of `width` and the provided [`color`][LCDColor].
@@ -834,7 +1828,7 @@ impl Graphics {
             p2.x,
             p2.y,
             width,
-            color.into(),
+            (&color).into(),
         )
     }
 
@@ -859,13 +1853,57 @@ impl Graphics {
             (*self.0).fillPolygon,
             n_pts as i32,
             coords_seq.as_mut_ptr(),
-            color.into(),
+            (&color).into(),
             fillrule
         )?;
 
         Ok(())
     }
 
+    /// Draws a line `length` pixels long from `origin` in the direction of `direction`, with
+    /// a stroke width of `width`. `direction` only sets the angle; it's normalized, so its own
+    /// length doesn't matter. Nothing is drawn for a zero `direction`.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_ray(
+        &self,
+        origin: ScreenPoint,
+        direction: ScreenVector,
+        length: f32,
+        width: i32,
+        color: LCDColor,
+    ) -> Result<(), Error> {
+        let direction = direction.to_f32();
+        if direction == Vector2D::zero() {
+            return Ok(());
+        }
+        let offset = (direction.normalize() * length).round().to_i32();
+        self.draw_line(origin, origin + offset, width, color)
+    }
+
+    /// Draws the outline of the polygon with vertices at `coords`, as lines `width` pixels wide
+    /// joining each vertex to the next. If `closed` is `true`, a final line joins the last
+    /// vertex back to the first.
+    ///
+    /// Each line is drawn with [Graphics::draw_line()], so they share its line cap style.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_polygon(
+        &self,
+        coords: &[ScreenPoint],
+        width: i32,
+        color: LCDColor,
+        closed: bool,
+    ) -> Result<(), Error> {
+        for pair in coords.windows(2) {
+            self.draw_line(pair[0], pair[1], width, color.clone())?;
+        }
+        if closed && coords.len() > 2 {
+            self.draw_line(coords[coords.len() - 1], coords[0], width, color)?;
+        }
+        Ok(())
+    }
+
     /// Draws a filled triangle with points at `p1`, `p2`, and `p3` with the provided [`color`][LCDColor].
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.fillTriangle)
@@ -884,7 +1922,7 @@ impl Graphics {
             p2.y,
             p3.x,
             p3.y,
-            color.into(),
+            (&color).into(),
         )
     }
 
@@ -898,7 +1936,7 @@ impl Graphics {
             rect.origin.y,
             rect.size.width,
             rect.size.height,
-            color.into(),
+            (&color).into(),
         )
     }
 
@@ -912,10 +1950,58 @@ impl Graphics {
             rect.origin.y,
             rect.size.width,
             rect.size.height,
-            color.into(),
+            (&color).into(),
         )
     }
 
+    /// Fills each rectangle in `rects` with its color, for drawing many rectangles at once,
+    /// like particles.
+    ///
+    /// The SDK has no batched fill, so this still makes one call per rectangle, and grouping
+    /// by color wouldn't save anything since every call takes its own color. It does borrow
+    /// each color in place rather than cloning it, so pattern colors stay valid for the call.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn fill_rects(&self, rects: &[(ScreenRect, LCDColor)]) -> Result<(), Error> {
+        for (rect, color) in rects {
+            pd_func_caller!(
+                (*self.0).fillRect,
+                rect.origin.x,
+                rect.origin.y,
+                rect.size.width,
+                rect.size.height,
+                color.into(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Fills `rect` with a checkerboard of light and dark gray squares `cell` pixels across,
+    /// like the background image editors show behind transparent areas.
+    ///
+    /// Intended as a debugging aid: draw it behind sprites to see which of their pixels are
+    /// masked out.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_transparency_grid(&self, rect: ScreenRect, cell: i32) -> Result<(), Error> {
+        ensure!(cell > 0, "Transparency grid cell size must be positive, got {}", cell);
+        let light = LCDColor::Pattern(dither::gray(192));
+        let dark = LCDColor::Pattern(dither::gray(64));
+        for row in 0..(rect.size.height + cell - 1) / cell {
+            for column in 0..(rect.size.width + cell - 1) / cell {
+                let cell_rect = ScreenRect::new(
+                    rect.origin + ScreenVector::new(column * cell, row * cell),
+                    ScreenSize::new(cell, cell),
+                );
+                if let Some(cell_rect) = cell_rect.intersection(&rect) {
+                    let color = if (row + column) % 2 == 0 { &light } else { &dark };
+                    self.fill_rect(cell_rect, color.clone())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Draws a filled ellipse inside the rectangle `size` at position `origin`
     /// 
     /// * The ellipse will be drawn inset within the rectangle bounds.
@@ -943,30 +2029,51 @@ impl Graphics {
             line_width,
             start_angle,
             end_angle,
-            color.into(),
+            (&color).into(),
+        )
+    }
+
+    /// Draws an arc of the circle centered on `center` with the given `radius`, from
+    /// `start_angle` to `end_angle`, with a line `width` pixels wide drawn inside the circle.
+    ///
+    /// Angles are given in degrees, clockwise from due north, as for [Graphics::draw_ellipse()].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_arc(
+        &self,
+        center: ScreenPoint,
+        radius: i32,
+        start_angle: f32,
+        end_angle: f32,
+        width: i32,
+        color: LCDColor,
+    ) -> Result<(), Error> {
+        self.draw_ellipse(
+            center - ScreenVector::new(radius, radius),
+            ScreenSize::new(radius * 2, radius * 2),
+            width,
+            start_angle,
+            end_angle,
+            color,
         )
     }
 
     /// Draws a solid ellipse inside the rectangle `size` at position `origin`
     /// 
     /// * The ellipse will be drawn inset within the rectangle bounds.
-    /// * The line will be drawn in the provided `line_width` and [`color`][LCDColor]
+    /// * The ellipse will be filled with the provided [`color`][LCDColor]
     /// * If `start_angle == end_angle`, this draws a complete ellipse.
     /// * If `start_angle != end_angle`, this draws an wedge (or "pacman") shape between the given angles.
     /// * Angles are given in degrees, clockwise from due north.
     /// 
-    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.drawEllipse)
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.fillEllipse)
     pub fn fill_ellipse(
         &self,
-        target: OptionalBitmap,
-        stencil: OptionalBitmap,
         origin: ScreenPoint,
         size: ScreenSize,
-        line_width: i32,
         start_angle: f32,
         end_angle: f32,
         color: LCDColor,
-        clip: LCDRect,
     ) -> Result<(), Error> {
         pd_func_caller!(
             (*self.0).fillEllipse,
@@ -976,10 +2083,133 @@ impl Graphics {
             size.height,
             start_angle,
             end_angle,
-            color.into(),
+            (&color).into(),
         )
     }
 
+    /// Draws the outline of a rectangle with corners rounded to `radius`, which is clamped to
+    /// half the rectangle's smaller side. The outline is `width` pixels wide, drawn inside
+    /// `rect`.
+    ///
+    /// The Playdate C API has no rounded rectangle, so this draws the straight edges with
+    /// rectangles and each corner with a quarter ellipse arc.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_rounded_rect(
+        &self,
+        rect: ScreenRect,
+        radius: i32,
+        width: i32,
+        color: LCDColor,
+    ) -> Result<(), Error> {
+        let radius = radius
+            .min(rect.size.width / 2)
+            .min(rect.size.height / 2)
+            .max(0);
+        let ScreenRect { origin, size } = rect;
+        let diameter = radius * 2;
+
+        let edges = [
+            (origin.x + radius, origin.y, size.width - diameter, width),
+            (origin.x + radius, origin.y + size.height - width, size.width - diameter, width),
+            (origin.x, origin.y + radius, width, size.height - diameter),
+            (origin.x + size.width - width, origin.y + radius, width, size.height - diameter),
+        ];
+        for (x, y, edge_width, edge_height) in edges {
+            self.fill_rect(
+                ScreenRect::new(
+                    ScreenPoint::new(x, y),
+                    ScreenSize::new(edge_width, edge_height),
+                ),
+                color.clone(),
+            )?;
+        }
+        if radius == 0 {
+            return Ok(());
+        }
+
+        let right = origin.x + size.width - diameter;
+        let bottom = origin.y + size.height - diameter;
+        let corners = [
+            (origin.x, origin.y, 270.0),
+            (right, origin.y, 0.0),
+            (right, bottom, 90.0),
+            (origin.x, bottom, 180.0),
+        ];
+        for (x, y, start_angle) in corners {
+            self.draw_ellipse(
+                ScreenPoint::new(x, y),
+                ScreenSize::new(diameter, diameter),
+                width,
+                start_angle,
+                start_angle + 90.0,
+                color.clone(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draws a filled rectangle with corners rounded to `radius`, which is clamped to half
+    /// the rectangle's smaller side.
+    ///
+    /// The Playdate C API has no rounded rectangle, so this fills the straight edges with
+    /// rectangles and each corner with a quarter ellipse, without overlapping them.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn fill_rounded_rect(
+        &self,
+        rect: ScreenRect,
+        radius: i32,
+        color: LCDColor,
+    ) -> Result<(), Error> {
+        let radius = radius
+            .min(rect.size.width / 2)
+            .min(rect.size.height / 2)
+            .max(0);
+        if radius == 0 {
+            return self.fill_rect(rect, color);
+        }
+        let ScreenRect { origin, size } = rect;
+        let diameter = radius * 2;
+
+        // Full-height middle column, then the left and right columns between the corners.
+        self.fill_rect(
+            ScreenRect::new(
+                origin + ScreenVector::new(radius, 0),
+                ScreenSize::new(size.width - diameter, size.height),
+            ),
+            color.clone(),
+        )?;
+        for x in [origin.x, origin.x + size.width - radius] {
+            self.fill_rect(
+                ScreenRect::new(
+                    ScreenPoint::new(x, origin.y + radius),
+                    ScreenSize::new(radius, size.height - diameter),
+                ),
+                color.clone(),
+            )?;
+        }
+
+        let right = origin.x + size.width - diameter;
+        let bottom = origin.y + size.height - diameter;
+        let corners = [
+            (origin.x, origin.y, 270.0),
+            (right, origin.y, 0.0),
+            (right, bottom, 90.0),
+            (origin.x, bottom, 180.0),
+        ];
+        for (x, y, start_angle) in corners {
+            self.fill_ellipse(
+                ScreenPoint::new(x, y),
+                ScreenSize::new(diameter, diameter),
+                start_angle,
+                start_angle + 90.0,
+                color.clone(),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Load the font at `path` into a [Font] object.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.loadFont)
@@ -1001,6 +2231,70 @@ impl Graphics {
         }
     }
 
+    /// Returns a [Font] made from font data in memory, for fonts packed into other assets
+    /// rather than stored as separate files.
+    ///
+    /// `data` must be the contents of an uncompressed `.pft` file minus its 16-byte header:
+    /// the 12-byte `Playdate FNT` magic and the 4-byte flags after it. `wide` must match the
+    /// header's flag saying the font has glyphs at codepoints above U+1FFFF. Data that still
+    /// starts with the magic is rejected.
+    ///
+    /// The data is copied and kept alive for as long as the font, as the SDK doesn't copy it.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.makeFontFromData)
+    pub fn make_font_from_data(&self, data: &[u8], wide: bool) -> Result<Font, Error> {
+        ensure!(
+            !data.starts_with(b"Playdate FNT"),
+            "make_font_from_data needs the font data without its 16-byte .pft header"
+        );
+        let mut data = data.to_vec();
+        let font = pd_func_caller!(
+            (*self.0).makeFontFromData,
+            data.as_mut_ptr() as *mut crankstart_sys::LCDFontData,
+            wide as c_int
+        )?;
+        let mut font = Font::new(font)?;
+        font.1 = Some(data);
+        Ok(font)
+    }
+
+    /// Sets the tracking, the extra space in pixels between letters, used by
+    /// [Graphics::draw_text()]. Pass the same value to [Graphics::get_text_width()] to
+    /// measure text as it will be drawn.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setTextTracking)
+    pub fn set_text_tracking(&self, tracking: i32) -> Result<(), Error> {
+        pd_func_caller!((*self.0).setTextTracking, tracking)?;
+        unsafe { TEXT_TRACKING = tracking };
+        Ok(())
+    }
+
+    /// Returns the tracking last set with [Graphics::set_text_tracking()], or 0 if it
+    /// hasn't been set.
+    ///
+    /// The C API has no way to read the tracking back, so this is tracked by crankstart and
+    /// won't reflect changes made directly through the SDK.
+    pub fn get_text_tracking(&self) -> i32 {
+        unsafe { TEXT_TRACKING }
+    }
+
+    /// Sets the leading, the extra space in pixels between lines of text, used by
+    /// [Graphics::draw_text()]. It can be negative to pull lines closer together, and stays
+    /// set until changed.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.setTextLeading)
+    pub fn set_text_leading(&self, leading: i32) -> Result<(), Error> {
+        pd_func_caller!((*self.0).setTextLeading, leading)?;
+        unsafe { TEXT_LEADING = leading };
+        Ok(())
+    }
+
+    /// Returns the leading last set with [Graphics::set_text_leading()], or 0 if it hasn't
+    /// been set. Like [Graphics::get_text_tracking()], this is tracked by crankstart.
+    pub fn get_text_leading(&self) -> i32 {
+        unsafe { TEXT_LEADING }
+    }
+
     /// Sets the [font][Font] to use in subsequent [Graphics::draw_text()] calls.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.loadFont)
@@ -1027,7 +2321,65 @@ impl Graphics {
         )
     }
 
+    /// Draws `text` in `font` at the top left of `rect`, shortened according to `strategy`
+    /// if it is wider than `rect`. Returns `true` if the text was shortened.
+    ///
+    /// Only the width of `rect` is considered, and the text is measured as a single line with
+    /// the current [tracking][Graphics::set_text_tracking()]. Leaves `font` set as the
+    /// current font, as [Graphics::set_font()] would.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_text_fit(
+        &self,
+        font: &Font,
+        text: &str,
+        rect: ScreenRect,
+        strategy: FitStrategy,
+    ) -> Result<bool, Error> {
+        const ELLIPSIS: &str = "…";
+        let tracking = self.get_text_tracking();
+        self.set_font(font)?;
+        if self.get_text_width(font, text, tracking)? <= rect.size.width {
+            self.draw_text(text, rect.origin)?;
+            return Ok(false);
+        }
+        let available = match strategy {
+            FitStrategy::Truncate => rect.size.width,
+            FitStrategy::Ellipsize => {
+                rect.size.width - self.get_text_width(font, ELLIPSIS, tracking)?
+            }
+        };
+        if available < 0 {
+            // Not even the ellipsis fits.
+            return Ok(true);
+        }
+
+        // Binary search the character boundaries for the longest prefix that fits.
+        let boundaries = text.char_indices().map(|(i, _)| i).collect::<Vec<_>>();
+        let (mut fits, mut too_wide) = (0, boundaries.len());
+        while too_wide - fits > 1 {
+            let middle = (fits + too_wide) / 2;
+            if self.get_text_width(font, &text[..boundaries[middle]], tracking)? <= available {
+                fits = middle;
+            } else {
+                too_wide = middle;
+            }
+        }
+        let prefix = &text[..boundaries[fits]];
+
+        match strategy {
+            FitStrategy::Truncate => self.draw_text(prefix, rect.origin)?,
+            FitStrategy::Ellipsize => {
+                self.draw_text(&format!("{}{}", prefix, ELLIPSIS), rect.origin)?
+            }
+        };
+        Ok(true)
+    }
+
     /// Returns the width of the given `text` in the given [font][Font].
+    ///
+    /// The text is measured as a single line, even if it contains `'\n'`; use
+    /// [Graphics::get_text_width_multiline()] to size multi-line text.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.getTextWidth)
     pub fn get_text_width(&self, font: &Font, text: &str, tracking: i32) -> Result<i32, Error> {
@@ -1042,6 +2394,158 @@ impl Graphics {
         )
     }
 
+    /// Returns the width of the widest line of `text` in the given [font][Font],
+    /// where lines are separated by `'\n'`.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_text_width_multiline(
+        &self,
+        font: &Font,
+        text: &str,
+        tracking: i32,
+    ) -> Result<i32, Error> {
+        let mut width = 0;
+        for line in text.split('\n') {
+            width = width.max(self.get_text_width(font, line, tracking)?);
+        }
+        Ok(width)
+    }
+
+    /// Returns the height of `text` in the given [font][Font], counting one font height
+    /// for each line separated by `'\n'`, plus the [leading][Graphics::set_text_leading()]
+    /// between lines.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_text_height(&self, font: &Font, text: &str) -> Result<i32, Error> {
+        let line_count = text.split('\n').count() as i32;
+        self.lines_height(font, line_count)
+    }
+
+    /// Internal function; the height of `line_count` lines of text in `font`.
+    fn lines_height(&self, font: &Font, line_count: i32) -> Result<i32, Error> {
+        let leading = self.get_text_leading() * (line_count - 1).max(0);
+        Ok(line_count * self.get_font_height(font)? as i32 + leading)
+    }
+
+    /// Returns how tall `text` will be in the given [font][Font] when wrapped to `width`
+    /// pixels, without drawing it. Lines are separated by `'\n'` as well as by wrapping, and
+    /// the [leading][Graphics::set_text_leading()] is added between them.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_text_height_in_rect(
+        &self,
+        font: &Font,
+        text: &str,
+        width: i32,
+        wrap: TextWrap,
+    ) -> Result<i32, Error> {
+        let line_count = self.wrap_text(font, text, width, wrap)?.len() as i32;
+        self.lines_height(font, line_count)
+    }
+
+    /// Internal function; returns the byte length of the longest prefix of `text` that fits
+    /// in `width` pixels at the current tracking, but at least one character so wrapping
+    /// always makes progress.
+    pub(crate) fn fitting_prefix(
+        &self,
+        font: &Font,
+        text: &str,
+        width: i32,
+    ) -> Result<usize, Error> {
+        let tracking = self.get_text_tracking();
+        let mut boundaries = text.char_indices().map(|(i, _)| i).skip(1).collect::<Vec<_>>();
+        boundaries.push(text.len());
+        let (mut fits, mut too_wide) = (0, boundaries.len());
+        while too_wide - fits > 1 {
+            let middle = (fits + too_wide) / 2;
+            if self.get_text_width(font, &text[..boundaries[middle]], tracking)? <= width {
+                fits = middle;
+            } else {
+                too_wide = middle;
+            }
+        }
+        Ok(boundaries[fits])
+    }
+
+    /// Internal function; splits `text` into the lines it takes up when wrapped to `width`.
+    fn wrap_text<'a>(
+        &self,
+        font: &Font,
+        text: &'a str,
+        width: i32,
+        wrap: TextWrap,
+    ) -> Result<Vec<&'a str>, Error> {
+        let tracking = self.get_text_tracking();
+        let fits = |text: &str| -> Result<bool, Error> {
+            Ok(self.get_text_width(font, text, tracking)? <= width)
+        };
+        let fitting_prefix = |text: &str| self.fitting_prefix(font, text, width);
+
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            match wrap {
+                TextWrap::Clip => lines.push(paragraph),
+                TextWrap::Character => {
+                    let mut rest = paragraph;
+                    while !fits(rest)? {
+                        let (line, remainder) = rest.split_at(fitting_prefix(rest)?);
+                        lines.push(line);
+                        rest = remainder;
+                    }
+                    lines.push(rest);
+                }
+                TextWrap::Word => {
+                    let mut line_start = 0;
+                    let mut line_end = None;
+                    let mut word_start = 0;
+                    for word in paragraph.split(' ') {
+                        let word_end = word_start + word.len();
+                        if !word.is_empty() {
+                            if !fits(&paragraph[line_start..word_end])? {
+                                if let Some(end) = line_end {
+                                    lines.push(&paragraph[line_start..end]);
+                                    line_start = word_start;
+                                }
+                                while !fits(&paragraph[line_start..word_end])? {
+                                    let split = fitting_prefix(&paragraph[line_start..word_end])?;
+                                    lines.push(&paragraph[line_start..line_start + split]);
+                                    line_start += split;
+                                }
+                            }
+                            line_end = Some(word_end);
+                        }
+                        word_start = word_end + 1;
+                    }
+                    lines.push(&paragraph[line_start..line_end.unwrap_or(line_start)]);
+                }
+            }
+        }
+        Ok(lines)
+    }
+
+    /// Draws `text` in `font` into a new transparent bitmap just big enough to hold it, so
+    /// static text can be drawn every frame as a cheap bitmap copy. Lines are separated by
+    /// `'\n'`, and the [tracking][Graphics::set_text_tracking()] is the current one.
+    ///
+    /// Leaves `font` set as the current font, as [Graphics::set_font()] would.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn render_text_to_bitmap(&self, font: &Font, text: &str) -> Result<Bitmap, Error> {
+        let size = ScreenSize::new(
+            self.get_text_width_multiline(font, text, self.get_text_tracking())?,
+            self.get_text_height(font, text)?,
+        );
+        ensure!(
+            size.width > 0 && size.height > 0,
+            "Text {:?} has nothing to render",
+            text
+        );
+        let bitmap = self.new_bitmap(size, LCDColor::Solid(LCDSolidColor::kColorClear))?;
+        self.set_font(font)?;
+        self.with_context(&bitmap, || self.draw_text(text, ScreenPoint::zero()))?;
+        Ok(bitmap)
+    }
+
     /// Returns the height of the given [font][Font].
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.getFontHeight)
@@ -1074,4 +2578,72 @@ impl Graphics {
             tracking,
         )
     }
+
+    /// Draws a spinning arrow centered on `position` prompting the player to use the crank,
+    /// like the Lua SDK's crank indicator. Call it every frame while the crank is needed.
+    ///
+    /// The indicator hides itself while the crank is undocked and turning, and returns
+    /// whether it was drawn. Turning is detected from [System::get_crank_angle()], so this
+    /// doesn't consume the [System::get_crank_change()] your game reads.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn draw_crank_indicator(
+        &self,
+        system: &System,
+        position: ScreenPoint,
+    ) -> Result<bool, Error> {
+        // (sin, cos) of every 30 degrees, clockwise from straight up.
+        const DIRECTIONS: [(f32, f32); 12] = [
+            (0.0, 1.0),
+            (0.5, 0.866),
+            (0.866, 0.5),
+            (1.0, 0.0),
+            (0.866, -0.5),
+            (0.5, -0.866),
+            (0.0, -1.0),
+            (-0.5, -0.866),
+            (-0.866, -0.5),
+            (-1.0, 0.0),
+            (-0.866, 0.5),
+            (-0.5, 0.866),
+        ];
+        const RADIUS: i32 = 14;
+
+        let angle = system.get_crank_angle()?;
+        let last_angle =
+            unsafe { (*core::ptr::addr_of_mut!(CRANK_INDICATOR_LAST_ANGLE)).replace(angle) };
+        let turning = last_angle.is_some_and(|last| last != angle);
+        if turning && !system.is_crank_docked()? {
+            return Ok(false);
+        }
+
+        let step = system.get_current_time_milliseconds()? / 100 % DIRECTIONS.len();
+        let start_angle = step as f32 * 30.0;
+        let black = LCDColor::Solid(LCDSolidColor::kColorBlack);
+        self.draw_ellipse(
+            position - ScreenVector::new(RADIUS, RADIUS),
+            ScreenSize::new(RADIUS * 2, RADIUS * 2),
+            3,
+            start_angle,
+            start_angle + 270.0,
+            black.clone(),
+        )?;
+
+        // Arrowhead at the leading end of the arc, pointing clockwise along it.
+        let (sin, cos) = DIRECTIONS[(step + 9) % DIRECTIONS.len()];
+        let at = |distance: f32, forward: f32| {
+            ScreenPoint::new(
+                position.x + (sin * distance + cos * forward) as i32,
+                position.y + (-cos * distance + sin * forward) as i32,
+            )
+        };
+        let radius = RADIUS as f32 - 1.5;
+        self.fill_triangle(
+            at(radius - 6.0, 0.0),
+            at(radius + 6.0, 0.0),
+            at(radius, 7.0),
+            black,
+        )?;
+        Ok(true)
+    }
 }
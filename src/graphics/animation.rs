@@ -0,0 +1,136 @@
+use {
+    crate::{
+        geometry::ScreenPoint,
+        graphics::{BitmapTable, LCDBitmapFlip},
+    },
+    anyhow::{ensure, Error},
+    core::time::Duration,
+};
+
+/// How an [Animation] moves through its frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlayMode {
+    /// Play from the first frame to the last, then start over, forever.
+    Loop,
+    /// Play from the first frame to the last, then hold the last frame.
+    Once,
+    /// Play from the first frame to the last and back to the first, then hold the first frame.
+    PingPong,
+}
+
+/// A sprite-sheet animation that plays the frames of a [BitmapTable] at a fixed rate.
+///
+/// Call [Animation::update()] with the time since the last frame, then [Animation::draw()].
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     core::time::Duration,
+/// #     crankstart::graphics::{Animation, Graphics, LCDBitmapFlip, PlayMode},
+/// #     euclid::point2,
+/// # };
+/// # fn main() -> Result<(), Error> {
+/// # let graphics = Graphics::get();
+/// let table = graphics.load_bitmap_table("images/walk")?;
+/// let mut walk = Animation::new(table, 8, 12.0, PlayMode::Loop)?;
+/// // every frame:
+/// walk.update(Duration::from_millis(33));
+/// walk.draw(point2(100, 100), LCDBitmapFlip::kBitmapUnflipped)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Animation {
+    table: BitmapTable,
+    frame_count: usize,
+    fps: f32,
+    mode: PlayMode,
+    elapsed: Duration,
+    frame: usize,
+    finished: bool,
+}
+
+impl Animation {
+    /// Create an animation over the first `frame_count` frames of `table`, shown at `fps`
+    /// frames per second.
+    pub fn new(
+        table: BitmapTable,
+        frame_count: usize,
+        fps: f32,
+        mode: PlayMode,
+    ) -> Result<Self, Error> {
+        ensure!(frame_count > 0, "An animation needs at least one frame");
+        ensure!(fps > 0.0, "Animation fps must be positive, got {}", fps);
+        Ok(Self {
+            table,
+            frame_count,
+            fps,
+            mode,
+            elapsed: Duration::ZERO,
+            frame: 0,
+            finished: false,
+        })
+    }
+
+    /// The index of the frame currently shown.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    pub fn mode(&self) -> PlayMode {
+        self.mode
+    }
+
+    /// Returns `true` once a [PlayMode::Once] or [PlayMode::PingPong] animation has played
+    /// through. [PlayMode::Loop] animations never finish.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Start again from the first frame.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.frame = 0;
+        self.finished = false;
+    }
+
+    /// Switch to `mode` and start again from the first frame.
+    pub fn set_mode(&mut self, mode: PlayMode) {
+        self.mode = mode;
+        self.reset();
+    }
+
+    /// Advance the animation by `delta`.
+    pub fn update(&mut self, delta: Duration) {
+        if self.finished {
+            return;
+        }
+        self.elapsed += delta;
+        let step = (self.elapsed.as_secs_f32() * self.fps) as usize;
+        let last = self.frame_count - 1;
+        match self.mode {
+            PlayMode::Loop => self.frame = step % self.frame_count,
+            PlayMode::Once => {
+                self.frame = step.min(last);
+                self.finished = step >= self.frame_count;
+            }
+            PlayMode::PingPong => {
+                // Forward through every frame, then back without repeating either end.
+                let round_trip = (last * 2).max(1);
+                if step >= round_trip {
+                    self.frame = 0;
+                    self.finished = true;
+                } else if step <= last {
+                    self.frame = step;
+                } else {
+                    self.frame = round_trip - step;
+                }
+            }
+        }
+    }
+
+    /// Draw the current frame with its upper-left corner at `location`.
+    pub fn draw(&self, location: ScreenPoint, flip: LCDBitmapFlip) -> Result<(), Error> {
+        self.table.get_bitmap(self.frame)?.draw(location, flip)
+    }
+}
@@ -0,0 +1,143 @@
+use {crate::system::System, anyhow::Error};
+
+/// Scrolls a list or other tall content with the crank, with momentum that carries on after
+/// the crank stops and eases out with friction.
+///
+/// Call [CrankScroller::update()] once per frame and use the returned offset to position
+/// your content, e.g. with
+/// [`Graphics::set_draw_offset(vec2(0, -offset))`][crate::graphics::Graphics::set_draw_offset].
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     crankstart::{
+/// #         graphics::{CrankScroller, Graphics},
+/// #         system::System,
+/// #     },
+/// #     euclid::vec2,
+/// # };
+/// # const ROW_HEIGHT: i32 = 20;
+/// # fn main() -> Result<(), Error> {
+/// # let rows = 30;
+/// let mut scroller = CrankScroller::new().with_snap(ROW_HEIGHT);
+/// // every frame:
+/// let offset = scroller.update(&System::get(), rows * ROW_HEIGHT, 240)?;
+/// Graphics::get().set_draw_offset(vec2(0, -offset))?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrankScroller {
+    offset: f32,
+    velocity: f32,
+    sensitivity: f32,
+    friction: f32,
+    snap: Option<i32>,
+}
+
+impl Default for CrankScroller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrankScroller {
+    /// Velocities below this many pixels per frame are treated as stopped.
+    const MIN_VELOCITY: f32 = 0.5;
+
+    /// Create a scroller at offset 0, moving one pixel per degree of crank rotation with a
+    /// friction of `0.9`.
+    pub fn new() -> Self {
+        Self {
+            offset: 0.0,
+            velocity: 0.0,
+            sensitivity: 1.0,
+            friction: 0.9,
+            snap: None,
+        }
+    }
+
+    /// Set how many pixels to scroll per degree of crank rotation.
+    /// Negative values reverse the scroll direction.
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Set how much velocity is kept each frame once the crank stops, between 0 (stop
+    /// immediately) and 1 (coast forever).
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Once the scroller comes to rest, ease it to the nearest multiple of `page` pixels,
+    /// for paged lists.
+    pub fn with_snap(mut self, page: i32) -> Self {
+        self.snap = if page > 0 { Some(page) } else { None };
+        self
+    }
+
+    /// The current scroll offset, in pixels.
+    pub fn offset(&self) -> i32 {
+        self.offset as i32
+    }
+
+    /// Jump to `offset` and stop any momentum.
+    pub fn set_offset(&mut self, offset: i32) {
+        self.offset = offset as f32;
+        self.velocity = 0.0;
+    }
+
+    /// Returns `true` while the scroller is still moving under its own momentum.
+    pub fn is_moving(&self) -> bool {
+        self.velocity.abs() >= Self::MIN_VELOCITY
+    }
+
+    /// Read the crank change for this frame and advance the scroll position. Returns the
+    /// new offset, clamped to `0..=(content_height - view_height)`.
+    pub fn update(
+        &mut self,
+        system: &System,
+        content_height: i32,
+        view_height: i32,
+    ) -> Result<i32, Error> {
+        let change = system.get_crank_change()?;
+        Ok(self.update_with_change(change, content_height, view_height))
+    }
+
+    /// Like [CrankScroller::update()], with the crank change in degrees supplied by the caller.
+    pub fn update_with_change(
+        &mut self,
+        crank_change: f32,
+        content_height: i32,
+        view_height: i32,
+    ) -> i32 {
+        if crank_change != 0.0 {
+            self.velocity = crank_change * self.sensitivity;
+        } else {
+            self.velocity *= self.friction;
+            if !self.is_moving() {
+                self.velocity = 0.0;
+            }
+        }
+        self.offset += self.velocity;
+
+        if let (Some(page), false) = (self.snap, self.is_moving() || crank_change != 0.0) {
+            let target = (self.offset / page as f32 + 0.5) as i32 * page;
+            let distance = target as f32 - self.offset;
+            self.offset = if distance.abs() < 1.0 {
+                target as f32
+            } else {
+                self.offset + distance / 2.0
+            };
+        }
+
+        let max_offset = (content_height - view_height).max(0) as f32;
+        if self.offset < 0.0 || self.offset > max_offset {
+            self.offset = self.offset.clamp(0.0, max_offset);
+            self.velocity = 0.0;
+        }
+        self.offset()
+    }
+}
@@ -0,0 +1,66 @@
+//! Ordered-dither 8x8 patterns for gray fills and translucent overlays.
+//!
+//! These are convenience functions and not from the original Playdate C API
+
+use crate::graphics::{LCDPattern, LCDSolidColor};
+
+/// 8x8 Bayer matrix; a pixel is set when its entry is below the fill threshold, which
+/// spreads set pixels as evenly as possible at every level.
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// Returns one byte per row with a bit set, MSB first, for each pixel covered at
+/// `coverage`, where 0 covers nothing and 255 covers every pixel.
+///
+/// `const` so patterns like [Pattern::DITHER_50][crate::graphics::Pattern::DITHER_50] can be
+/// built at compile time, hence the `while` loops.
+const fn coverage_rows(coverage: u8) -> [u8; 8] {
+    let threshold = (coverage as u32 * 64 + 127) / 255;
+    let mut rows = [0; 8];
+    let mut y = 0;
+    while y < 8 {
+        let mut x = 0;
+        while x < 8 {
+            if (BAYER_8X8[y][x] as u32) < threshold {
+                rows[y] |= 0x80 >> x;
+            }
+            x += 1;
+        }
+        y += 1;
+    }
+    rows
+}
+
+/// An opaque gray pattern, from 0 (black) to 255 (white).
+pub const fn gray(level: u8) -> LCDPattern {
+    let rows = coverage_rows(level);
+    let mut pattern = [0xff; 16];
+    let mut y = 0;
+    while y < 8 {
+        pattern[y] = rows[y];
+        y += 1;
+    }
+    pattern
+}
+
+/// A pattern with `coverage` of its pixels, from 0 (none) to 255 (all), drawn in `color`
+/// and the rest transparent, for shading whatever is already on screen.
+///
+/// `color` should be black or white; any other color is treated as black.
+pub fn overlay(coverage: u8, color: LCDSolidColor) -> LCDPattern {
+    let mask = coverage_rows(coverage);
+    let mut pattern = [0; 16];
+    if color == LCDSolidColor::kColorWhite {
+        pattern[..8].copy_from_slice(&mask);
+    }
+    pattern[8..].copy_from_slice(&mask);
+    pattern
+}
@@ -0,0 +1,134 @@
+use {
+    crate::{
+        geometry::{ScreenPoint, ScreenRect, ScreenSize},
+        graphics::{dither, Graphics, LCDColor, LCDSolidColor, LCD_COLUMNS, LCD_ROWS},
+    },
+    alloc::{vec, vec::Vec},
+    anyhow::{ensure, Error},
+    core::time::Duration,
+    euclid::point2,
+};
+
+/// A fog-of-war overlay for tile maps: each tile has a visibility level from 0 (hidden,
+/// drawn solid black) to `max_level` (fully visible, not drawn), with levels in between
+/// shaded with a black [dither overlay][dither::overlay].
+///
+/// Tiles changed with [FogOverlay::reveal()] fade towards their new level as
+/// [FogOverlay::update()] is called; [FogOverlay::set_level()] changes them immediately.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct FogOverlay {
+    columns: usize,
+    rows: usize,
+    max_level: u8,
+    levels_per_second: f32,
+    current: Vec<f32>,
+    target: Vec<u8>,
+}
+
+impl FogOverlay {
+    /// Create a fully hidden overlay of `columns` by `rows` tiles. Revealed tiles fade in at
+    /// `max_level` levels per second, so a full reveal takes one second.
+    pub fn new(columns: usize, rows: usize, max_level: u8) -> Self {
+        let max_level = max_level.max(1);
+        Self {
+            columns,
+            rows,
+            max_level,
+            levels_per_second: max_level as f32,
+            current: vec![0.0; columns * rows],
+            target: vec![0; columns * rows],
+        }
+    }
+
+    /// Set how many levels per second tiles move towards their revealed level.
+    pub fn with_reveal_speed(mut self, levels_per_second: f32) -> Self {
+        self.levels_per_second = levels_per_second;
+        self
+    }
+
+    fn index(&self, column: usize, row: usize) -> Result<usize, Error> {
+        ensure!(
+            column < self.columns && row < self.rows,
+            "Tile ({}, {}) is outside the {}x{} fog overlay",
+            column,
+            row,
+            self.columns,
+            self.rows
+        );
+        Ok(row * self.columns + column)
+    }
+
+    /// The level a tile is currently drawn at, rounded to the nearest whole level.
+    pub fn level(&self, column: usize, row: usize) -> Result<u8, Error> {
+        Ok((self.current[self.index(column, row)?] + 0.5) as u8)
+    }
+
+    /// Set a tile's visibility immediately, without fading.
+    pub fn set_level(&mut self, column: usize, row: usize, level: u8) -> Result<(), Error> {
+        let index = self.index(column, row)?;
+        let level = level.min(self.max_level);
+        self.target[index] = level;
+        self.current[index] = level as f32;
+        Ok(())
+    }
+
+    /// Fade a tile towards `level` over the following calls to [FogOverlay::update()].
+    pub fn reveal(&mut self, column: usize, row: usize, level: u8) -> Result<(), Error> {
+        let index = self.index(column, row)?;
+        self.target[index] = level.min(self.max_level);
+        Ok(())
+    }
+
+    /// Advance any fading tiles by `delta`.
+    pub fn update(&mut self, delta: Duration) {
+        let step = self.levels_per_second * delta.as_secs_f32();
+        for (current, &target) in self.current.iter_mut().zip(self.target.iter()) {
+            let target = target as f32;
+            *current = if *current < target {
+                (*current + step).min(target)
+            } else {
+                (*current - step).max(target)
+            };
+        }
+    }
+
+    /// Draw the fog over tiles of `tile_size`, where `camera` is the world position drawn at
+    /// the top left of the screen. Tiles that are fully visible or off screen aren't drawn.
+    pub fn draw(
+        &self,
+        graphics: &Graphics,
+        camera: ScreenPoint,
+        tile_size: ScreenSize,
+    ) -> Result<(), Error> {
+        ensure!(
+            tile_size.width > 0 && tile_size.height > 0,
+            "Fog tile size must be positive"
+        );
+        let first_column = (camera.x / tile_size.width).max(0) as usize;
+        let first_row = (camera.y / tile_size.height).max(0) as usize;
+        let last_column = ((camera.x + LCD_COLUMNS as i32) / tile_size.width + 1).max(0) as usize;
+        let last_row = ((camera.y + LCD_ROWS as i32) / tile_size.height + 1).max(0) as usize;
+
+        for row in first_row..last_row.min(self.rows) {
+            for column in first_column..last_column.min(self.columns) {
+                let visibility = self.current[row * self.columns + column] / self.max_level as f32;
+                let color = if visibility >= 1.0 {
+                    continue;
+                } else if visibility <= 0.0 {
+                    LCDColor::Solid(LCDSolidColor::kColorBlack)
+                } else {
+                    let coverage = ((1.0 - visibility) * 255.0) as u8;
+                    LCDColor::Pattern(dither::overlay(coverage, LCDSolidColor::kColorBlack))
+                };
+                let origin = point2(
+                    column as i32 * tile_size.width - camera.x,
+                    row as i32 * tile_size.height - camera.y,
+                );
+                graphics.fill_rect(ScreenRect::new(origin, tile_size), color)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use {
+    crate::{
+        geometry::ScreenRect,
+        graphics::{Bitmap, Graphics},
+    },
+    anyhow::{ensure, Error},
+    euclid::{point2, rect, vec2},
+};
+
+/// A bitmap that can be stretched to any size without distorting its border, for UI panels
+/// and buttons.
+///
+/// The `inner` rect splits the bitmap into nine parts. The four corners are always drawn
+/// at their original size, the edges are stretched along their length, and the center is
+/// stretched to fill what's left.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct NineSlice {
+    bitmap: Bitmap,
+    inner: ScreenRect,
+    width: i32,
+    height: i32,
+}
+
+/// One row or column of the nine-slice grid: where it is in the source bitmap, where it
+/// goes in the destination, and whether it stretches to fit.
+struct Band {
+    source_start: i32,
+    source_end: i32,
+    dest_start: i32,
+    dest_end: i32,
+    stretch: bool,
+}
+
+impl Band {
+    /// Split a source length of `source_len` with an inner band from `inner_start` to
+    /// `inner_end` across a destination from `dest_start` to `dest_end`.
+    fn split(
+        source_len: i32,
+        inner_start: i32,
+        inner_end: i32,
+        dest_start: i32,
+        dest_end: i32,
+    ) -> [Band; 3] {
+        let before = inner_start;
+        let after = source_len - inner_end;
+        let dest_len = dest_end - dest_start;
+        // If the destination is too small for both borders, give each border its share
+        // and crop them rather than squashing them.
+        let (middle_start, middle_end) = if before + after > dest_len {
+            let split = dest_start + dest_len * before / (before + after).max(1);
+            (split, split)
+        } else {
+            (dest_start + before, dest_end - after)
+        };
+        [
+            Band {
+                source_start: 0,
+                source_end: inner_start,
+                dest_start,
+                dest_end: middle_start,
+                stretch: false,
+            },
+            Band {
+                source_start: inner_start,
+                source_end: inner_end,
+                dest_start: middle_start,
+                dest_end: middle_end,
+                stretch: true,
+            },
+            Band {
+                source_start: inner_end,
+                source_end: source_len,
+                dest_start: middle_end,
+                dest_end,
+                stretch: false,
+            },
+        ]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.source_end <= self.source_start || self.dest_end <= self.dest_start
+    }
+
+    /// The scale to draw the bitmap at along this band, and where the bitmap's origin goes.
+    fn placement(&self, anchor_end: bool) -> (f32, i32) {
+        if self.stretch {
+            let scale = (self.dest_end - self.dest_start) as f32
+                / (self.source_end - self.source_start) as f32;
+            let origin = self.dest_start - (self.source_start as f32 * scale) as i32;
+            (scale, origin)
+        } else if anchor_end {
+            (1.0, self.dest_end - self.source_end)
+        } else {
+            (1.0, self.dest_start - self.source_start)
+        }
+    }
+}
+
+impl NineSlice {
+    /// Create a nine-slice from `bitmap`, with `inner` marking the stretchable center in
+    /// bitmap coordinates. `inner` must lie within the bitmap.
+    pub fn new(bitmap: Bitmap, inner: ScreenRect) -> Result<Self, Error> {
+        let size = bitmap.get_size()?;
+        ensure!(
+            inner.origin.x >= 0
+                && inner.origin.y >= 0
+                && inner.max_x() <= size.width
+                && inner.max_y() <= size.height
+                && !inner.size.is_empty(),
+            "Inner rect of a nine-slice must be a non-empty rect within the bitmap"
+        );
+        Ok(Self {
+            bitmap,
+            inner,
+            width: size.width,
+            height: size.height,
+        })
+    }
+
+    /// The bitmap being sliced.
+    pub fn bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    /// The stretchable center of the bitmap.
+    pub fn inner(&self) -> ScreenRect {
+        self.inner
+    }
+
+    /// Draw the nine-slice stretched to fill `dest`.
+    pub fn draw_in_rect(&self, dest: ScreenRect) -> Result<(), Error> {
+        let graphics = Graphics::get();
+        let columns = Band::split(
+            self.width,
+            self.inner.origin.x,
+            self.inner.max_x(),
+            dest.origin.x,
+            dest.max_x(),
+        );
+        let rows = Band::split(
+            self.height,
+            self.inner.origin.y,
+            self.inner.max_y(),
+            dest.origin.y,
+            dest.max_y(),
+        );
+        for (row_index, row) in rows.iter().enumerate() {
+            if row.is_empty() {
+                continue;
+            }
+            let (scale_y, y) = row.placement(row_index == 2);
+            for (column_index, column) in columns.iter().enumerate() {
+                if column.is_empty() {
+                    continue;
+                }
+                let (scale_x, x) = column.placement(column_index == 2);
+                let region = rect(
+                    column.dest_start,
+                    row.dest_start,
+                    column.dest_end - column.dest_start,
+                    row.dest_end - row.dest_start,
+                );
+                graphics.with_clip(region, || {
+                    self.bitmap
+                        .draw_scaled(point2(x, y), vec2(scale_x, scale_y))
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
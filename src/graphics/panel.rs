@@ -0,0 +1,140 @@
+use {
+    crate::{
+        geometry::{ScreenPoint, ScreenRect},
+        graphics::{Graphics, LCDColor, LCDSolidColor},
+    },
+    alloc::{string::String, vec::Vec},
+    anyhow::Error,
+    euclid::{point2, size2},
+};
+
+#[derive(Clone, Debug)]
+enum PanelRow {
+    Label(String),
+    Button(String),
+    Value(String, String),
+}
+
+/// A simple vertical panel of text rows for debug menus and other quick UIs.
+///
+/// Rows are added with [Panel::label()], [Panel::button()] and [Panel::value()], and the
+/// whole panel is drawn in the system font by [Panel::draw()], which sizes the panel to fit
+/// its widest row. Rebuild the panel whenever its contents change; it's cheap.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     crankstart::graphics::{Graphics, Panel},
+/// #     euclid::point2,
+/// # };
+/// # fn main() -> Result<(), Error> {
+/// # let fps = 30.0;
+/// let buttons = Panel::new()
+///     .label("Debug")
+///     .value("FPS", format!("{:.1}", fps))
+///     .button("Reset level")
+///     .draw(&Graphics::get(), point2(10, 10))?;
+/// // buttons[0] is the rect of "Reset level"
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Panel {
+    rows: Vec<PanelRow>,
+}
+
+impl Panel {
+    /// Space between the panel border and row text, and between a value's label and value.
+    const PADDING: i32 = 4;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a row of plain text.
+    pub fn label<S: Into<String>>(mut self, text: S) -> Self {
+        self.rows.push(PanelRow::Label(text.into()));
+        self
+    }
+
+    /// Add a row of outlined text; its rect is returned by [Panel::draw()].
+    pub fn button<S: Into<String>>(mut self, text: S) -> Self {
+        self.rows.push(PanelRow::Button(text.into()));
+        self
+    }
+
+    /// Add a row with `label` on the left and `value` right-aligned.
+    pub fn value<L: Into<String>, V: Into<String>>(mut self, label: L, value: V) -> Self {
+        self.rows.push(PanelRow::Value(label.into(), value.into()));
+        self
+    }
+
+    /// Lay out the panel with its top left corner at `origin`, returning the panel's rect
+    /// and the rect of each row, in the order they were added.
+    pub fn layout(
+        &self,
+        graphics: &Graphics,
+        origin: ScreenPoint,
+    ) -> Result<(ScreenRect, Vec<ScreenRect>), Error> {
+        let row_height = graphics.get_system_font_height() as i32 + Self::PADDING * 2;
+        let mut content_width = 0;
+        for row in &self.rows {
+            let width = match row {
+                PanelRow::Label(text) | PanelRow::Button(text) => {
+                    graphics.get_system_text_width(text, 0)?
+                }
+                PanelRow::Value(label, value) => {
+                    graphics.get_system_text_width(label, 0)?
+                        + Self::PADDING
+                        + graphics.get_system_text_width(value, 0)?
+                }
+            };
+            content_width = content_width.max(width);
+        }
+        let row_width = content_width + Self::PADDING * 2;
+        let rows = (0..self.rows.len() as i32)
+            .map(|i| {
+                ScreenRect::new(
+                    point2(origin.x, origin.y + i * row_height),
+                    size2(row_width, row_height),
+                )
+            })
+            .collect::<Vec<_>>();
+        let panel = ScreenRect::new(
+            origin,
+            size2(row_width, row_height * self.rows.len() as i32),
+        );
+        Ok((panel, rows))
+    }
+
+    /// Draw the panel with its top left corner at `origin`, returning the rect of each
+    /// button in the order the buttons were added, for hit testing.
+    pub fn draw(&self, graphics: &Graphics, origin: ScreenPoint) -> Result<Vec<ScreenRect>, Error> {
+        let black = LCDColor::Solid(LCDSolidColor::kColorBlack);
+        let (panel, rows) = self.layout(graphics, origin)?;
+        graphics.fill_rect(panel, LCDColor::Solid(LCDSolidColor::kColorWhite))?;
+        graphics.draw_rect(panel, black.clone())?;
+
+        let mut buttons = Vec::new();
+        for (row, rect) in self.rows.iter().zip(rows) {
+            let text_origin = point2(rect.origin.x + Self::PADDING, rect.origin.y + Self::PADDING);
+            match row {
+                PanelRow::Label(text) => {
+                    graphics.draw_text(text, text_origin)?;
+                }
+                PanelRow::Button(text) => {
+                    graphics.draw_rect(rect.inflate(-1, -1), black.clone())?;
+                    graphics.draw_text(text, text_origin)?;
+                    buttons.push(rect);
+                }
+                PanelRow::Value(label, value) => {
+                    graphics.draw_text(label, text_origin)?;
+                    let value_x =
+                        rect.max_x() - Self::PADDING - graphics.get_system_text_width(value, 0)?;
+                    graphics.draw_text(value, point2(value_x, text_origin.y))?;
+                }
+            }
+        }
+        Ok(buttons)
+    }
+}
@@ -0,0 +1,128 @@
+use {
+    crate::{
+        geometry::{ScreenPoint, ScreenSize},
+        graphics::{Bitmap, LCDBitmapFlip, LCD_COLUMNS, LCD_ROWS},
+    },
+    alloc::vec::Vec,
+    anyhow::Error,
+    crankstart_sys::floor_to_i32,
+    euclid::point2,
+};
+
+/// A background layer that scrolls at a fraction of the camera's speed.
+///
+/// A `factor` of 0 keeps the layer fixed on screen, 1 moves it with the camera like the
+/// rest of the world, and values in between make it appear further away.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct ParallaxLayer {
+    bitmap: Bitmap,
+    factor: f32,
+    repeat: bool,
+}
+
+impl ParallaxLayer {
+    /// Create a layer drawing `bitmap` once, scrolled by `factor` times the camera position.
+    pub fn new(bitmap: Bitmap, factor: f32) -> Self {
+        Self {
+            bitmap,
+            factor,
+            repeat: false,
+        }
+    }
+
+    /// Tile the bitmap in both directions to fill the screen wherever the camera is.
+    pub fn repeating(mut self) -> Self {
+        self.repeat = true;
+        self
+    }
+
+    /// Where the layer's bitmap is drawn on screen for a camera at `camera`.
+    /// For repeating layers this is the top left of the tile covering the screen's origin.
+    pub fn offset(&self, camera: ScreenPoint) -> Result<ScreenPoint, Error> {
+        // Round towards negative infinity so the layer moves evenly either side of 0.
+        let scroll = |position: i32| -floor_to_i32(position as f32 * self.factor);
+        let scrolled = point2(scroll(camera.x), scroll(camera.y));
+        if !self.repeat {
+            return Ok(scrolled);
+        }
+        let size = self.bitmap.get_size()?;
+        if size.is_empty() {
+            return Ok(scrolled);
+        }
+        Ok(point2(
+            -(-scrolled.x).rem_euclid(size.width),
+            -(-scrolled.y).rem_euclid(size.height),
+        ))
+    }
+
+    /// Draw the layer for a camera at `camera`.
+    pub fn draw(&self, camera: ScreenPoint) -> Result<(), Error> {
+        let offset = self.offset(camera)?;
+        if self.repeat {
+            let size = ScreenSize::new(LCD_COLUMNS as i32 - offset.x, LCD_ROWS as i32 - offset.y);
+            self.bitmap
+                .tile(offset, size, LCDBitmapFlip::kBitmapUnflipped)
+        } else {
+            self.bitmap.draw(offset, LCDBitmapFlip::kBitmapUnflipped)
+        }
+    }
+}
+
+/// A stack of [ParallaxLayer]s, drawn back to front in the order they were added.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     crankstart::graphics::{Graphics, Parallax, ParallaxLayer},
+/// #     euclid::point2,
+/// # };
+/// # fn main() -> Result<(), Error> {
+/// # let graphics = Graphics::get();
+/// # let sky = graphics.load_bitmap("images/sky")?;
+/// # let hills = graphics.load_bitmap("images/hills")?;
+/// # let trees = graphics.load_bitmap("images/trees")?;
+/// # let camera = point2(0, 0);
+/// let parallax = Parallax::new()
+///     .with_layer(ParallaxLayer::new(sky, 0.0))
+///     .with_layer(ParallaxLayer::new(hills, 0.25).repeating())
+///     .with_layer(ParallaxLayer::new(trees, 0.5).repeating());
+/// // every frame:
+/// parallax.draw(camera)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Parallax {
+    layers: Vec<ParallaxLayer>,
+}
+
+impl Parallax {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer in front of the existing ones.
+    pub fn with_layer(mut self, layer: ParallaxLayer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Add a layer in front of the existing ones.
+    pub fn push(&mut self, layer: ParallaxLayer) {
+        self.layers.push(layer);
+    }
+
+    pub fn layers(&self) -> &[ParallaxLayer] {
+        &self.layers
+    }
+
+    /// Draw every layer for a camera at `camera`, back to front.
+    pub fn draw(&self, camera: ScreenPoint) -> Result<(), Error> {
+        for layer in &self.layers {
+            layer.draw(camera)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use {
+    crate::{
+        geometry::{ScreenPoint, ScreenVector},
+        graphics::{Graphics, LCDColor, LCDPolygonFillRule},
+        pd_func_caller,
+    },
+    alloc::vec::Vec,
+    anyhow::{ensure, Error},
+    euclid::point2,
+};
+
+/// A polygon that keeps its vertices in the flattened `x, y, x, y, ...` layout the SDK
+/// wants, so drawing it every frame doesn't allocate.
+///
+/// Static shapes can be built once; moving shapes can be updated in place with
+/// [Polygon::translate()] and [Polygon::set_vertex()].
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     crankstart::graphics::{LCDColor, LCDPolygonFillRule, LCDSolidColor, Polygon},
+/// #     euclid::{point2, vec2},
+/// # };
+/// # fn main() -> Result<(), Error> {
+/// let mut ship = Polygon::new(&[point2(0, -8), point2(6, 8), point2(-6, 8)]);
+/// ship.translate(vec2(200, 120));
+/// ship.fill(LCDColor::Solid(LCDSolidColor::kColorBlack), LCDPolygonFillRule::kPolygonFillNonZero)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Polygon {
+    coords: Vec<i32>,
+}
+
+impl Polygon {
+    /// Create a polygon with the given vertices.
+    pub fn new(points: &[ScreenPoint]) -> Self {
+        Self {
+            coords: points.iter().flat_map(|pt| [pt.x, pt.y]).collect(),
+        }
+    }
+
+    /// The number of vertices.
+    pub fn len(&self) -> usize {
+        self.coords.len() / 2
+    }
+
+    /// Returns `true` if the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// The vertex at `index`, if there is one.
+    pub fn vertex(&self, index: usize) -> Option<ScreenPoint> {
+        let x = *self.coords.get(index * 2)?;
+        let y = *self.coords.get(index * 2 + 1)?;
+        Some(point2(x, y))
+    }
+
+    /// Iterates over the vertices in order.
+    pub fn vertices(&self) -> impl Iterator<Item = ScreenPoint> + '_ {
+        self.coords
+            .chunks_exact(2)
+            .map(|pair| point2(pair[0], pair[1]))
+    }
+
+    /// Move the vertex at `index` to `point`.
+    pub fn set_vertex(&mut self, index: usize, point: ScreenPoint) -> Result<(), Error> {
+        ensure!(
+            index < self.len(),
+            "Vertex {} out of range for polygon with {} vertices",
+            index,
+            self.len()
+        );
+        self.coords[index * 2] = point.x;
+        self.coords[index * 2 + 1] = point.y;
+        Ok(())
+    }
+
+    /// Add a vertex after the last one.
+    pub fn push(&mut self, point: ScreenPoint) {
+        self.coords.push(point.x);
+        self.coords.push(point.y);
+    }
+
+    /// Move every vertex by `offset`.
+    pub fn translate(&mut self, offset: ScreenVector) {
+        for pair in self.coords.chunks_exact_mut(2) {
+            pair[0] += offset.x;
+            pair[1] += offset.y;
+        }
+    }
+
+    /// Fill the polygon with `color`, using `fillrule` to decide which parts of a
+    /// self-intersecting polygon are inside.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-graphics.fillPolygon)
+    pub fn fill(&self, color: LCDColor, fillrule: LCDPolygonFillRule) -> Result<(), Error> {
+        let graphics = Graphics::get();
+        // fillPolygon takes a mutable pointer but only reads the coordinates
+        pd_func_caller!(
+            (*graphics.0).fillPolygon,
+            self.len() as i32,
+            self.coords.as_ptr() as *mut i32,
+            (&color).into(),
+            fillrule
+        )
+    }
+
+    /// Draw the outline of the polygon with lines `width` pixels wide, closing it back to
+    /// the first vertex. Like [Graphics::draw_polygon()], a polygon with fewer than 3
+    /// vertices isn't closed.
+    pub fn draw(&self, color: LCDColor, width: i32) -> Result<(), Error> {
+        let graphics = Graphics::get();
+        for pair in self.coords.windows(4).step_by(2) {
+            let start = point2(pair[0], pair[1]);
+            let end = point2(pair[2], pair[3]);
+            graphics.draw_line(start, end, width, color.clone())?;
+        }
+        if self.len() > 2 {
+            let coords = &self.coords;
+            let first = point2(coords[0], coords[1]);
+            let last = point2(coords[coords.len() - 2], coords[coords.len() - 1]);
+            graphics.draw_line(last, first, width, color)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use {
+    crate::{
+        geometry::ScreenVector,
+        graphics::{Bitmap, Graphics, LCDBitmapFlip, LCDColor, LCDSolidColor},
+    },
+    anyhow::Error,
+    euclid::{point2, vec2},
+};
+
+// Unit directions cycled through by `ScreenShake::shake`, chosen so consecutive frames jump
+// across the origin rather than drifting in one direction.
+const SHAKE_DIRECTIONS: [(f32, f32); 8] = [
+    (1.0, 0.0),
+    (-0.7, 0.7),
+    (0.0, -1.0),
+    (0.7, 0.7),
+    (-1.0, 0.0),
+    (0.7, -0.7),
+    (0.0, 1.0),
+    (-0.7, -0.7),
+];
+
+/// A screen-shake effect that snapshots the frame once and re-blits it at an offset each
+/// frame, so the scene doesn't need to be redrawn while shaking.
+///
+/// Areas of the screen exposed by the offset are filled with the background color.
+///
+/// ```no_run
+/// # use {anyhow::Error, crankstart::graphics::{Graphics, ScreenShake}};
+/// # fn main() -> Result<(), Error> {
+/// // after drawing the scene once
+/// let mut shake = ScreenShake::new(6.0, 0.8)?;
+/// // then, every frame until it settles
+/// if !shake.shake(&Graphics::get())? {
+///     // done shaking, resume normal drawing
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScreenShake {
+    snapshot: Bitmap,
+    amplitude: f32,
+    decay: f32,
+    frame: usize,
+    background: LCDSolidColor,
+}
+
+impl ScreenShake {
+    /// Amplitudes below this many pixels are treated as finished.
+    const MIN_AMPLITUDE: f32 = 0.5;
+
+    /// Snapshot the current frame buffer and start a shake of `amplitude` pixels, which is
+    /// multiplied by `decay` (between 0 and 1) after every [ScreenShake::shake()] call.
+    pub fn new(amplitude: f32, decay: f32) -> Result<Self, Error> {
+        let snapshot = Graphics::get().get_framebuffer_bitmap()?;
+        Ok(Self {
+            snapshot,
+            amplitude,
+            decay: decay.clamp(0.0, 1.0),
+            frame: 0,
+            background: LCDSolidColor::kColorWhite,
+        })
+    }
+
+    /// Use `color` for the areas of the screen exposed by the offset. Defaults to white.
+    pub fn with_background(mut self, color: LCDSolidColor) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Re-capture the frame buffer, e.g. after the scene underneath has changed.
+    pub fn recapture(&mut self) -> Result<(), Error> {
+        self.snapshot = Graphics::get().get_framebuffer_bitmap()?;
+        Ok(())
+    }
+
+    /// Start shaking again with a new `amplitude`, keeping the current snapshot.
+    pub fn restart(&mut self, amplitude: f32) {
+        self.amplitude = amplitude;
+        self.frame = 0;
+    }
+
+    /// The current shake amplitude, in pixels.
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    /// Returns `true` once the amplitude has decayed to nothing.
+    pub fn is_finished(&self) -> bool {
+        self.amplitude < Self::MIN_AMPLITUDE
+    }
+
+    /// Clear the screen to the background color and draw the snapshot moved by `offset`.
+    ///
+    /// An offset of zero reproduces the captured frame exactly.
+    pub fn apply(&self, graphics: &Graphics, offset: ScreenVector) -> Result<(), Error> {
+        graphics.clear(LCDColor::Solid(self.background))?;
+        self.snapshot
+            .draw(point2(offset.x, offset.y), LCDBitmapFlip::kBitmapUnflipped)
+    }
+
+    /// Returns the offset the next call to [ScreenShake::shake()] will use.
+    pub fn next_offset(&self) -> ScreenVector {
+        if self.is_finished() {
+            return vec2(0, 0);
+        }
+        let (x, y) = SHAKE_DIRECTIONS[self.frame % SHAKE_DIRECTIONS.len()];
+        vec2((x * self.amplitude) as i32, (y * self.amplitude) as i32)
+    }
+
+    /// Draw one frame of the shake and decay the amplitude.
+    ///
+    /// Returns `true` while still shaking. Once the amplitude has decayed, the snapshot is
+    /// drawn back at its original position and `false` is returned.
+    pub fn shake(&mut self, graphics: &Graphics) -> Result<bool, Error> {
+        if self.is_finished() {
+            self.apply(graphics, vec2(0, 0))?;
+            return Ok(false);
+        }
+        self.apply(graphics, self.next_offset())?;
+        self.frame += 1;
+        self.amplitude *= self.decay;
+        Ok(true)
+    }
+}
@@ -0,0 +1,104 @@
+use {
+    crate::{
+        geometry::ScreenPoint,
+        graphics::{Font, Graphics, LCD_COLUMNS},
+    },
+    anyhow::Error,
+    core::fmt,
+};
+
+/// Draws text at an advancing position on screen, so `write!` and `writeln!` can be used
+/// for quick on-screen debugging output.
+///
+/// Text wraps at the right edge of the screen, and `'\n'` moves to the start of the next
+/// line, one font height plus the [leading][Graphics::set_text_leading()] further down.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     core::fmt::Write,
+/// #     crankstart::graphics::{Graphics, TextCursor},
+/// #     euclid::point2,
+/// # };
+/// # fn main() -> Result<(), Error> {
+/// # let font = Graphics::get().load_font("fonts/small")?;
+/// # let (x, y) = (12, 34);
+/// let mut cursor = TextCursor::new(&font, point2(4, 4));
+/// writeln!(cursor, "x={}", x)?;
+/// writeln!(cursor, "y={}", y)?;
+/// # Ok(()) }
+/// ```
+pub struct TextCursor<'a> {
+    font: &'a Font,
+    left: i32,
+    position: ScreenPoint,
+}
+
+impl<'a> TextCursor<'a> {
+    /// Create a cursor drawing in `font`, starting at `origin`. Wrapped lines start at
+    /// `origin.x`.
+    pub fn new(font: &'a Font, origin: ScreenPoint) -> Self {
+        Self {
+            font,
+            left: origin.x,
+            position: origin,
+        }
+    }
+
+    /// Where the next text will be drawn.
+    pub fn position(&self) -> ScreenPoint {
+        self.position
+    }
+
+    /// Move the cursor to `position`. Wrapped lines start at `position.x` from then on.
+    pub fn set_position(&mut self, position: ScreenPoint) {
+        self.left = position.x;
+        self.position = position;
+    }
+
+    /// Move to the start of the next line.
+    pub fn newline(&mut self) -> Result<(), Error> {
+        let graphics = Graphics::get();
+        self.position.x = self.left;
+        self.position.y +=
+            graphics.get_font_height(self.font)? as i32 + graphics.get_text_leading();
+        Ok(())
+    }
+
+    /// Draw `text` at the cursor and advance past it.
+    pub fn draw(&mut self, text: &str) -> Result<(), Error> {
+        let graphics = Graphics::get();
+        graphics.set_font(self.font)?;
+        let tracking = graphics.get_text_tracking();
+        for (index, line) in text.split('\n').enumerate() {
+            if index > 0 {
+                self.newline()?;
+            }
+            let mut rest = line;
+            while !rest.is_empty() {
+                let available = LCD_COLUMNS as i32 - self.position.x;
+                let len = graphics.fitting_prefix(self.font, rest, available)?;
+                let width = graphics.get_text_width(self.font, &rest[..len], tracking)?;
+                if width > available && self.position.x > self.left {
+                    // Not even one character fits, so start a new line and try again.
+                    self.newline()?;
+                    continue;
+                }
+                graphics.draw_text(&rest[..len], self.position)?;
+                self.position.x += width;
+                rest = &rest[len..];
+                if !rest.is_empty() {
+                    self.newline()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Write for TextCursor<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.draw(s).map_err(|_| fmt::Error)
+    }
+}
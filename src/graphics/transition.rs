@@ -0,0 +1,106 @@
+use {
+    crate::{
+        geometry::{ScreenPoint, ScreenRect, ScreenSize},
+        graphics::{dither, Graphics, LCDColor, LCDSolidColor, LCD_COLUMNS, LCD_ROWS},
+    },
+    anyhow::Error,
+};
+
+/// Distance from the center of the screen to its corners, rounded up.
+const MAX_RADIUS: i32 = 234;
+
+/// A full-screen transition that covers the screen in black as its progress goes from 0
+/// (nothing covered) to 1 (the whole screen covered).
+///
+/// Draw it over the outgoing scene with progress rising from 0 to 1, then over the incoming
+/// scene with progress falling back to 0.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::graphics::{Graphics, Transition}};
+/// # fn main() -> Result<(), Error> {
+/// # let graphics = Graphics::get();
+/// # let elapsed_ms = 250;
+/// // every frame of the transition, after drawing the scene:
+/// Transition::IrisOut.draw(&graphics, elapsed_ms as f32 / 500.0)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transition {
+    /// A black circle grows from the center of the screen
+    IrisIn,
+    /// A circular hole in the center of the screen shrinks to nothing
+    IrisOut,
+    /// Black sweeps across the screen from right to left
+    WipeLeft,
+    /// Black sweeps across the screen from left to right
+    WipeRight,
+    /// Black pixels appear across the screen in a dither pattern
+    Dissolve,
+}
+
+impl Transition {
+    /// Draw the transition at `progress`, which is clamped to 0..=1.
+    pub fn draw(&self, graphics: &Graphics, progress: f32) -> Result<(), Error> {
+        let progress = progress.clamp(0.0, 1.0);
+        if progress == 0.0 {
+            return Ok(());
+        }
+        let black = LCDColor::Solid(LCDSolidColor::kColorBlack);
+        let (width, height) = (LCD_COLUMNS as i32, LCD_ROWS as i32);
+        let center = ScreenPoint::new(width / 2, height / 2);
+        match self {
+            Transition::IrisIn => {
+                let radius = (MAX_RADIUS as f32 * progress) as i32;
+                graphics.fill_ellipse(
+                    ScreenPoint::new(center.x - radius, center.y - radius),
+                    ScreenSize::new(radius * 2, radius * 2),
+                    0.0,
+                    0.0,
+                    black,
+                )
+            }
+            Transition::IrisOut => {
+                // A ring whose outer edge is past the corners, thickening until the hole
+                // in the middle closes.
+                let hole = (MAX_RADIUS as f32 * (1.0 - progress)) as i32;
+                if hole == 0 {
+                    return graphics
+                        .fill_rect(ScreenRect::from_size(ScreenSize::new(width, height)), black);
+                }
+                graphics.draw_ellipse(
+                    ScreenPoint::new(center.x - MAX_RADIUS, center.y - MAX_RADIUS),
+                    ScreenSize::new(MAX_RADIUS * 2, MAX_RADIUS * 2),
+                    MAX_RADIUS - hole,
+                    0.0,
+                    0.0,
+                    black,
+                )
+            }
+            Transition::WipeLeft => {
+                let covered = (width as f32 * progress) as i32;
+                graphics.fill_rect(
+                    ScreenRect::new(
+                        ScreenPoint::new(width - covered, 0),
+                        ScreenSize::new(covered, height),
+                    ),
+                    black,
+                )
+            }
+            Transition::WipeRight => {
+                let covered = (width as f32 * progress) as i32;
+                graphics.fill_rect(
+                    ScreenRect::from_size(ScreenSize::new(covered, height)),
+                    black,
+                )
+            }
+            Transition::Dissolve => {
+                let coverage = (progress * 255.0) as u8;
+                graphics.fill_rect(
+                    ScreenRect::from_size(ScreenSize::new(width, height)),
+                    LCDColor::Pattern(dither::overlay(coverage, LCDSolidColor::kColorBlack)),
+                )
+            }
+        }
+    }
+}
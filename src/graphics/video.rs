@@ -0,0 +1,180 @@
+use {
+    crate::{
+        graphics::{Bitmap, Graphics},
+        pd_func_caller, pd_func_caller_log,
+    },
+    alloc::rc::Rc,
+    anyhow::{anyhow, Error},
+    core::cell::RefCell,
+    crankstart_sys::{ctypes::c_char, LCDVideoPlayer},
+    cstr_core::CStr,
+};
+
+/// Information about a [VideoPlayer]'s video, from [VideoPlayer::get_info()].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VideoInfo {
+    /// The width of the video, in pixels
+    pub width: i32,
+    /// The height of the video, in pixels
+    pub height: i32,
+    /// The frame rate the video was encoded at, in frames per second
+    pub frame_rate: f32,
+    /// The number of frames in the video
+    pub frame_count: i32,
+    /// The frame last rendered with [VideoPlayer::render_frame()]
+    pub current_frame: i32,
+}
+
+#[derive(Debug)]
+pub struct VideoPlayerInner {
+    pub(crate) raw_player: *mut LCDVideoPlayer,
+    // Kept so the bitmap set with set_context outlives the player's use of it
+    context: Option<Bitmap>,
+}
+
+impl VideoPlayerInner {
+    fn video() -> *const crankstart_sys::playdate_video {
+        unsafe { (*Graphics::get_ptr()).video }
+    }
+
+    fn raw_error(&self) -> Result<*const c_char, Error> {
+        pd_func_caller!((*Self::video()).getError, self.raw_player)
+    }
+
+    /// The player's last error, or a generic message naming `function_name` if it has none.
+    fn error(&self, function_name: &str) -> Error {
+        match self.raw_error() {
+            Ok(message) if !message.is_null() => {
+                let message = unsafe { CStr::from_ptr(message).to_string_lossy() };
+                anyhow!("{} failed: {}", function_name, message)
+            }
+            _ => anyhow!(
+                "{} failed without providing an error message",
+                function_name
+            ),
+        }
+    }
+}
+
+impl Drop for VideoPlayerInner {
+    fn drop(&mut self) {
+        pd_func_caller_log!((*Self::video()).freePlayer, self.raw_player);
+    }
+}
+
+pub type VideoPlayerInnerPtr = Rc<RefCell<VideoPlayerInner>>;
+
+/// A `.pdv` video loaded with [Graphics::load_video()], rendered a frame at a time into a
+/// bitmap or the screen.
+///
+/// Note that [Clone]ing a VideoPlayer clones a reference to the same player. The player
+/// is freed when the last reference is dropped.
+#[derive(Clone, Debug)]
+pub struct VideoPlayer {
+    pub(crate) inner: VideoPlayerInnerPtr,
+}
+
+impl VideoPlayer {
+    pub(crate) fn new(raw_player: *mut LCDVideoPlayer) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(VideoPlayerInner {
+                raw_player,
+                context: None,
+            })),
+        }
+    }
+
+    /// Gets the video's size, frame rate, frame count, and the last frame rendered.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-video.getInfo)
+    pub fn get_info(&self) -> Result<VideoInfo, Error> {
+        let mut info = VideoInfo {
+            width: 0,
+            height: 0,
+            frame_rate: 0.0,
+            frame_count: 0,
+            current_frame: 0,
+        };
+        pd_func_caller!(
+            (*VideoPlayerInner::video()).getInfo,
+            self.inner.borrow().raw_player,
+            &mut info.width,
+            &mut info.height,
+            &mut info.frame_rate,
+            &mut info.frame_count,
+            &mut info.current_frame
+        )?;
+        Ok(info)
+    }
+
+    /// Gets the frame rate the video was encoded at, in frames per second.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_frame_rate(&self) -> Result<f32, Error> {
+        Ok(self.get_info()?.frame_rate)
+    }
+
+    /// Renders frames into `context` instead of the screen. The player keeps a reference to
+    /// the bitmap until it's given another context.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-video.setContext)
+    pub fn set_context(&self, context: &Bitmap) -> Result<(), Error> {
+        let mut inner = self.inner.borrow_mut();
+        let result = pd_func_caller!(
+            (*VideoPlayerInner::video()).setContext,
+            inner.raw_player,
+            context.inner.borrow().raw_bitmap
+        )?;
+        if result == 0 {
+            return Err(inner.error("setContext"));
+        }
+        inner.context = Some(context.clone());
+        Ok(())
+    }
+
+    /// Gets the bitmap frames are rendered into. If no context has been set, the player
+    /// allocates one the size of the video, which it owns.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-video.getContext)
+    pub fn get_context(&self) -> Result<Bitmap, Error> {
+        let inner = self.inner.borrow();
+        if let Some(context) = &inner.context {
+            return Ok(context.clone());
+        }
+        let raw_bitmap =
+            pd_func_caller!((*VideoPlayerInner::video()).getContext, inner.raw_player)?;
+        if raw_bitmap.is_null() {
+            return Err(inner.error("getContext"));
+        }
+        Ok(Bitmap::from_raw_parts(raw_bitmap, false))
+    }
+
+    /// Renders frames straight to the screen.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-video.useScreenContext)
+    pub fn use_screen_context(&self) -> Result<(), Error> {
+        let mut inner = self.inner.borrow_mut();
+        pd_func_caller!(
+            (*VideoPlayerInner::video()).useScreenContext,
+            inner.raw_player
+        )?;
+        inner.context = None;
+        Ok(())
+    }
+
+    /// Renders frame number `frame` into the current context.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-video.renderFrame)
+    pub fn render_frame(&self, frame: i32) -> Result<(), Error> {
+        let inner = self.inner.borrow();
+        let result = pd_func_caller!(
+            (*VideoPlayerInner::video()).renderFrame,
+            inner.raw_player,
+            frame
+        )?;
+        if result == 0 {
+            return Err(inner.error("renderFrame"));
+        }
+        Ok(())
+    }
+}
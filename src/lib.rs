@@ -1,4 +1,4 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![allow(internal_features)]
 #![feature(lang_items, alloc_error_handler, core_intrinsics)]
 #![allow(unused_variables, dead_code, unused_imports)]
@@ -29,7 +29,9 @@ use {
     alloc::boxed::Box,
     anyhow::Error,
     core::{fmt, panic::PanicInfo},
-    crankstart_sys::{playdate_sprite, LCDRect, LCDSprite, SpriteCollisionResponseType},
+    crankstart_sys::{
+        playdate_sprite, LCDRect, LCDSprite, PDSystemEvent, SpriteCollisionResponseType,
+    },
 };
 
 pub struct Playdate {
@@ -125,6 +127,92 @@ pub trait Game {
     fn draw_and_update_sprites(&self) -> bool {
         true
     }
+
+    /// Return `Some(self)` to receive system lifecycle events through [EventHandler].
+    fn event_handler(&mut self) -> Option<&mut dyn EventHandler> {
+        None
+    }
+}
+
+/// Lifecycle events sent by the Playdate OS to the game's event handler.
+///
+/// Implement the methods you care about, and return `Some(self)` from
+/// [Game::event_handler()] to receive them. Errors are logged to the console.
+pub trait EventHandler {
+    /// The game has been created, after `kEventInit`. A game created on `kEventInitLua`
+    /// with [crankstart_game!] is only created after this event, so it won't receive it.
+    fn on_init(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The Lua runtime has been set up, for C games with Lua enabled; `kEventInitLua`. This
+    /// comes after `kEventInit`.
+    fn on_init_lua(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The system menu was opened and the game is paused; `kEventPause`.
+    fn on_pause(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The system menu was closed; `kEventResume`.
+    fn on_resume(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The device was locked; `kEventLock`.
+    fn on_lock(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The device was unlocked; `kEventUnlock`.
+    fn on_unlock(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The player is quitting the game; `kEventTerminate`. Save now.
+    fn on_terminate(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// The device is about to sleep because its battery is nearly empty; `kEventLowPower`.
+    fn on_low_power(&mut self, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// A key was pressed on the computer's keyboard while running in the Simulator;
+    /// `kEventKeyPressed`.
+    fn on_key_pressed(&mut self, key: u32, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// A key was released on the computer's keyboard while running in the Simulator;
+    /// `kEventKeyReleased`.
+    fn on_key_released(&mut self, key: u32, playdate: &mut Playdate) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Calls the [EventHandler] method for `event`, passing `arg` to the ones that take it.
+fn dispatch_event(
+    handler: &mut dyn EventHandler,
+    event: PDSystemEvent,
+    arg: u32,
+    playdate: &mut Playdate,
+) -> Result<(), Error> {
+    match event {
+        PDSystemEvent::kEventInit => handler.on_init(playdate),
+        PDSystemEvent::kEventInitLua => handler.on_init_lua(playdate),
+        PDSystemEvent::kEventLock => handler.on_lock(playdate),
+        PDSystemEvent::kEventUnlock => handler.on_unlock(playdate),
+        PDSystemEvent::kEventPause => handler.on_pause(playdate),
+        PDSystemEvent::kEventResume => handler.on_resume(playdate),
+        PDSystemEvent::kEventTerminate => handler.on_terminate(playdate),
+        PDSystemEvent::kEventKeyPressed => handler.on_key_pressed(arg, playdate),
+        PDSystemEvent::kEventKeyReleased => handler.on_key_released(arg, playdate),
+        PDSystemEvent::kEventLowPower => handler.on_low_power(playdate),
+    }
 }
 
 pub type GamePtr<T> = Box<T>;
@@ -197,6 +285,19 @@ impl<T: 'static + Game> GameRunner<T> {
         }
     }
 
+    /// Passes a system event received after the game was created on to the game's
+    /// [EventHandler], then runs any [System::on_exit()] hooks it triggers.
+    pub fn handle_event(&mut self, event: PDSystemEvent, arg: u32) {
+        if let Some(handler) = self.game.as_mut().and_then(|game| game.event_handler()) {
+            if let Err(err) = dispatch_event(handler, event, arg, &mut self.playdate) {
+                log_to_console!("Error handling {event:?}: {err:#}")
+            }
+        }
+        if event == PDSystemEvent::kEventTerminate {
+            System::run_exit_hooks();
+        }
+    }
+
     pub fn playdate_sprite(&self) -> *const playdate_sprite {
         SpriteManager::get_mut().playdate_sprite
     }
@@ -246,7 +347,7 @@ macro_rules! crankstart_game {
             extern "C" fn eventHandler(
                 playdate: *mut PlaydateAPI,
                 event: PDSystemEvent,
-                _arg: u32,
+                arg: u32,
             ) -> crankstart_sys::ctypes::c_int {
                 if event == $pd_system_event {
                     // This would only fail if PlaydateAPI has null pointers, which shouldn't happen.
@@ -274,12 +375,16 @@ macro_rules! crankstart_game {
                         GAME_RUNNER = Some(GameRunner::new(game, playdate));
                     }
                 }
+                if let Some(game_runner) = unsafe { GAME_RUNNER.as_mut() } {
+                    game_runner.handle_event(event, arg);
+                }
                 0
             }
         }
     };
 }
 
+#[cfg(not(test))]
 fn abort_with_addr(addr: usize) -> ! {
     let p = addr as *mut i32;
     unsafe {
@@ -288,6 +393,7 @@ fn abort_with_addr(addr: usize) -> ! {
     core::intrinsics::abort()
 }
 
+#[cfg(not(test))]
 #[panic_handler]
 fn panic(#[allow(unused)] panic_info: &PanicInfo) -> ! {
     use arrayvec::ArrayString;
@@ -346,17 +452,19 @@ unsafe impl GlobalAlloc for PlaydateAllocator {
     }
 }
 
+#[cfg(not(test))]
 #[global_allocator]
 pub(crate) static mut A: PlaydateAllocator = PlaydateAllocator;
 
 // define what happens in an Out Of Memory (OOM) condition
+#[cfg(not(test))]
 #[alloc_error_handler]
 fn alloc_error(_layout: Layout) -> ! {
     System::log_to_console("Out of Memory\0");
     abort_with_addr(0xDEADFA11);
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 pub unsafe extern "C" fn memcpy(dest: *mut u8, src: *const u8, n: usize) -> *mut u8 {
     let mut i = 0;
@@ -367,7 +475,7 @@ pub unsafe extern "C" fn memcpy(dest: *mut u8, src: *const u8, n: usize) -> *mut
     dest
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 pub unsafe extern "C" fn memmove(dest: *mut u8, src: *const u8, n: usize) -> *mut u8 {
     if src < dest as *const u8 {
@@ -388,7 +496,7 @@ pub unsafe extern "C" fn memmove(dest: *mut u8, src: *const u8, n: usize) -> *mu
     dest
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 pub unsafe extern "C" fn memcmp(s1: *const u8, s2: *const u8, n: usize) -> i32 {
     let mut i = 0;
@@ -403,7 +511,7 @@ pub unsafe extern "C" fn memcmp(s1: *const u8, s2: *const u8, n: usize) -> i32 {
     0
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 pub unsafe extern "C" fn bcmp(s1: *const u8, s2: *const u8, n: usize) -> i32 {
     memcmp(s1, s2, n)
@@ -419,72 +527,81 @@ pub unsafe fn memset_internal(s: *mut u8, c: crankstart_sys::ctypes::c_int, n: u
     s
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 pub unsafe extern "C" fn memset(s: *mut u8, c: crankstart_sys::ctypes::c_int, n: usize) -> *mut u8 {
     memset_internal(s, c, n)
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 pub unsafe extern "C" fn __bzero(s: *mut u8, n: usize) {
     memset_internal(s, 0, n);
 }
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn _sbrk() {}
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(not(target_os = "windows"), not(test)))]
 #[no_mangle]
 pub extern "C" fn _write() {}
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(not(target_os = "windows"), not(test)))]
 #[no_mangle]
 pub extern "C" fn _close() {}
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(not(target_os = "windows"), not(test)))]
 #[no_mangle]
 pub extern "C" fn _lseek() {}
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(not(target_os = "windows"), not(test)))]
 #[no_mangle]
 pub extern "C" fn _read() {}
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn _fstat() {}
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn _isatty() {}
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(not(target_os = "windows"), not(test)))]
 #[no_mangle]
 pub extern "C" fn _exit() {}
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn _open() {}
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn _kill() {}
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn _getpid() {}
 
+#[cfg(not(test))]
 #[no_mangle]
 pub extern "C" fn rust_eh_personality() {
     unimplemented!();
 }
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(test)))]
 #[no_mangle]
 extern "C" fn _Unwind_Resume() {
     unimplemented!();
 }
 
+#[cfg(not(test))]
 #[no_mangle]
 extern "C" fn __exidx_start() {
     unimplemented!();
 }
 
+#[cfg(not(test))]
 #[no_mangle]
 extern "C" fn __exidx_end() {
     unimplemented!();
@@ -493,3 +610,125 @@ extern "C" fn __exidx_end() {
 #[cfg(target_os = "macos")]
 #[link(name = "System")]
 extern "C" {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        alloc::{string::String, vec::Vec},
+    };
+
+    /// Records which handler method each event reached.
+    #[derive(Default)]
+    struct RecordingHandler {
+        calls: Vec<String>,
+    }
+
+    impl EventHandler for RecordingHandler {
+        fn on_init(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("init".into());
+            Ok(())
+        }
+
+        fn on_init_lua(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("init_lua".into());
+            Ok(())
+        }
+
+        fn on_pause(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("pause".into());
+            Ok(())
+        }
+
+        fn on_resume(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("resume".into());
+            Ok(())
+        }
+
+        fn on_lock(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("lock".into());
+            Ok(())
+        }
+
+        fn on_unlock(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("unlock".into());
+            Ok(())
+        }
+
+        fn on_terminate(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("terminate".into());
+            Ok(())
+        }
+
+        fn on_low_power(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push("low_power".into());
+            Ok(())
+        }
+
+        fn on_key_pressed(&mut self, key: u32, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push(alloc::format!("key_pressed {}", key));
+            Ok(())
+        }
+
+        fn on_key_released(&mut self, key: u32, _: &mut Playdate) -> Result<(), Error> {
+            self.calls.push(alloc::format!("key_released {}", key));
+            Ok(())
+        }
+    }
+
+    fn test_playdate() -> Playdate {
+        Playdate {
+            playdate: core::ptr::null(),
+        }
+    }
+
+    #[test]
+    fn dispatch_event_calls_the_matching_method() {
+        let cases = [
+            (PDSystemEvent::kEventInit, "init"),
+            (PDSystemEvent::kEventInitLua, "init_lua"),
+            (PDSystemEvent::kEventLock, "lock"),
+            (PDSystemEvent::kEventUnlock, "unlock"),
+            (PDSystemEvent::kEventPause, "pause"),
+            (PDSystemEvent::kEventResume, "resume"),
+            (PDSystemEvent::kEventTerminate, "terminate"),
+            (PDSystemEvent::kEventKeyPressed, "key_pressed 65"),
+            (PDSystemEvent::kEventKeyReleased, "key_released 65"),
+            (PDSystemEvent::kEventLowPower, "low_power"),
+        ];
+        for (event, expected) in cases {
+            let mut handler = RecordingHandler::default();
+            dispatch_event(&mut handler, event, 65, &mut test_playdate()).unwrap();
+            assert_eq!(handler.calls, [expected], "for {:?}", event);
+        }
+    }
+
+    struct TestGame;
+
+    impl Game for TestGame {
+        fn update(&mut self, _: &mut Playdate) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn exit_hooks_run_only_on_terminate() {
+        use {alloc::rc::Rc, core::cell::Cell};
+
+        let runs = Rc::new(Cell::new(0));
+        let hook_runs = runs.clone();
+        System::get().on_exit(move || hook_runs.set(hook_runs.get() + 1));
+
+        let mut runner = GameRunner::new(Some(Box::new(TestGame)), test_playdate());
+        runner.handle_event(PDSystemEvent::kEventLowPower, 0);
+        runner.handle_event(PDSystemEvent::kEventPause, 0);
+        assert_eq!(runs.get(), 0);
+
+        runner.handle_event(PDSystemEvent::kEventTerminate, 0);
+        assert_eq!(runs.get(), 1);
+
+        // Hooks run once, so a second terminate finds none left.
+        runner.handle_event(PDSystemEvent::kEventTerminate, 0);
+        assert_eq!(runs.get(), 1);
+    }
+}
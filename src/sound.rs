@@ -19,16 +19,25 @@
 //! ```
 
 use crate::{pd_func_caller, pd_func_caller_log};
+use alloc::vec::Vec;
 use crankstart_sys::ctypes;
 
 use anyhow::{anyhow, ensure, Error, Result};
 use core::ptr;
 use cstr_core::CString;
 
+pub use crankstart_sys::SoundFormat;
+
+pub mod channel;
+pub use channel::{AudioSource, SoundChannel};
+pub mod microphone;
+pub use microphone::Microphone;
 pub mod sampleplayer;
 pub use sampleplayer::{AudioSample, SamplePlayer};
 pub mod fileplayer;
 pub use fileplayer::FilePlayer;
+pub mod synth;
+pub use synth::{SoundWaveform, Synth};
 
 // When the Playdate system struct is created, it passes the given playdate_sound to Sound::new,
 // which then replaces this.
@@ -44,10 +53,13 @@ pub struct Sound {
     raw_file_player: *const crankstart_sys::playdate_sound_fileplayer,
     raw_sample: *const crankstart_sys::playdate_sound_sample,
     raw_sample_player: *const crankstart_sys::playdate_sound_sampleplayer,
+    raw_synth: *const crankstart_sys::playdate_sound_synth,
+    raw_channel: *const crankstart_sys::playdate_sound_channel,
 }
 
-// Not implemented: addSource, removeSource, setMicCallback, and getHeadphoneState (waiting on
-// crankstart callback strategy), getDefaultChannel, addChannel, removeChannel.
+// Not implemented: addSource, removeSource, and getHeadphoneState (waiting on crankstart
+// callback strategy).  addChannel and removeChannel are handled by SoundChannel, and
+// setMicCallback by Microphone.
 impl Sound {
     const fn null() -> Self {
         Self {
@@ -55,6 +67,8 @@ impl Sound {
             raw_file_player: ptr::null(),
             raw_sample: ptr::null(),
             raw_sample_player: ptr::null(),
+            raw_synth: ptr::null(),
+            raw_channel: ptr::null(),
         }
     }
 
@@ -70,12 +84,18 @@ impl Sound {
         ensure!(!raw_sample.is_null(), "Null sound.sample");
         let raw_sample_player = unsafe { (*raw_sound).sampleplayer };
         ensure!(!raw_sample_player.is_null(), "Null sound.sampleplayer");
+        let raw_synth = unsafe { (*raw_sound).synth };
+        ensure!(!raw_synth.is_null(), "Null sound.synth");
+        let raw_channel = unsafe { (*raw_sound).channel };
+        ensure!(!raw_channel.is_null(), "Null sound.channel");
 
         let sound = Self {
             raw_sound,
             raw_file_player,
             raw_sample,
             raw_sample_player,
+            raw_synth,
+            raw_channel,
         };
         unsafe { SOUND = sound };
         Ok(())
@@ -106,6 +126,41 @@ impl Sound {
         SamplePlayer::new(self.raw_sample_player, raw_player)
     }
 
+    /// Get a `Synth` that can be used to generate sound effects and music.
+    pub fn get_synth(&self) -> Result<Synth> {
+        let raw_synth = pd_func_caller!((*self.raw_synth).newSynth)?;
+        ensure!(!raw_synth.is_null(), "Null returned from synth.newSynth");
+        Synth::new(self.raw_synth, raw_synth)
+    }
+
+    /// Get a new `SoundChannel`, already added to the mix, for playing sources at their own
+    /// volume and pan.
+    pub fn get_channel(&self) -> Result<SoundChannel> {
+        let raw_channel = pd_func_caller!((*self.raw_channel).newChannel)?;
+        ensure!(
+            !raw_channel.is_null(),
+            "Null returned from channel.newChannel"
+        );
+        let channel = SoundChannel::new(self.raw_sound, self.raw_channel, raw_channel, true)?;
+        pd_func_caller!((*self.raw_sound).addChannel, raw_channel)?;
+        Ok(channel)
+    }
+
+    /// Get the default `SoundChannel`, which plays every source not added to another channel.
+    pub fn get_default_channel(&self) -> Result<SoundChannel> {
+        let raw_channel = pd_func_caller!((*self.raw_sound).getDefaultChannel)?;
+        ensure!(
+            !raw_channel.is_null(),
+            "Null returned from sound.getDefaultChannel"
+        );
+        SoundChannel::new(self.raw_sound, self.raw_channel, raw_channel, false)
+    }
+
+    /// Get a `Microphone` for recording audio input.
+    pub fn get_microphone(&self) -> Result<Microphone> {
+        Microphone::new(self.raw_sound)
+    }
+
     /// Loads an `AudioSample` sound effect.  Assign it to a `SamplePlayer` with
     /// `SamplePlayer.set_sample`.
     pub fn load_audio_sample(&self, sample_path: &str) -> Result<AudioSample> {
@@ -119,6 +174,45 @@ impl Sound {
         AudioSample::new(self.raw_sample, raw_audio_sample)
     }
 
+    /// Makes an `AudioSample` from raw audio `data` in the given `format`, played back at
+    /// `sample_rate` samples per second. 16-bit formats are little-endian.
+    ///
+    /// The SDK plays the data in place rather than copying it, so the sample takes ownership
+    /// of it.
+    pub fn new_audio_sample_from_data(
+        &self,
+        mut data: Vec<u8>,
+        format: SoundFormat,
+        sample_rate: u32,
+    ) -> Result<AudioSample> {
+        let raw_audio_sample = pd_func_caller!(
+            (*self.raw_sample).newSampleFromData,
+            data.as_mut_ptr(),
+            format,
+            sample_rate,
+            data.len() as ctypes::c_int
+        )?;
+        ensure!(
+            !raw_audio_sample.is_null(),
+            "Null returned from sample.newSampleFromData"
+        );
+        AudioSample::new_with_data(self.raw_sample, raw_audio_sample, Some(data))
+    }
+
+    /// Makes an empty `AudioSample` with room for `byte_count` bytes of audio, e.g. for
+    /// recording into.
+    pub fn new_sample_buffer(&self, byte_count: usize) -> Result<AudioSample> {
+        let raw_audio_sample = pd_func_caller!(
+            (*self.raw_sample).newSampleBuffer,
+            byte_count as ctypes::c_int
+        )?;
+        ensure!(
+            !raw_audio_sample.is_null(),
+            "Null returned from sample.newSampleBuffer"
+        );
+        AudioSample::new(self.raw_sample, raw_audio_sample)
+    }
+
     /// Returns the sound engine's current time, in frames, 44.1k per second.
     pub fn get_current_time(&self) -> Result<ctypes::c_uint> {
         pd_func_caller!((*self.raw_sound).getCurrentTime)
@@ -0,0 +1,120 @@
+use crate::{pd_func_caller, pd_func_caller_log};
+
+use alloc::{rc::Rc, vec::Vec};
+use anyhow::{ensure, Result};
+
+/// Something that produces audio and can be routed through a `SoundChannel`: a `Synth`,
+/// `SamplePlayer`, or `FilePlayer`.
+pub trait AudioSource {
+    /// Internal: the raw pointer the SDK uses for this source.
+    fn raw_source(&self) -> *mut crankstart_sys::SoundSource;
+}
+
+/// A channel that sources are mixed into, with its own volume and pan, for layering music
+/// and sound effects independently.  Sources not added to a channel play on the default one.
+///
+/// The channel keeps a reference to each source added to it, so a source can't be freed
+/// while the channel still plays it.  Dropping the channel removes its sources and takes it
+/// out of the mix.
+pub struct SoundChannel {
+    raw_sound: *const crankstart_sys::playdate_sound,
+    raw_subsystem: *const crankstart_sys::playdate_sound_channel,
+    raw_channel: *mut crankstart_sys::SoundChannel,
+    owned: bool,
+    sources: Vec<Rc<dyn AudioSource>>,
+}
+
+impl core::fmt::Debug for SoundChannel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SoundChannel")
+            .field("raw_channel", &self.raw_channel)
+            .field("owned", &self.owned)
+            .field("sources", &self.sources.len())
+            .finish()
+    }
+}
+
+impl Drop for SoundChannel {
+    fn drop(&mut self) {
+        // Use _log to leak rather than fail
+        for source in &self.sources {
+            pd_func_caller_log!(
+                (*self.raw_subsystem).removeSource,
+                self.raw_channel,
+                source.raw_source()
+            );
+        }
+        if self.owned {
+            pd_func_caller_log!((*self.raw_sound).removeChannel, self.raw_channel);
+            pd_func_caller_log!((*self.raw_subsystem).freeChannel, self.raw_channel);
+        }
+    }
+}
+
+// Not implemented: newChannel (use Sound::get_channel), addCallbackSource (waiting on
+// crankstart callback strategy), effects, and modulators.
+impl SoundChannel {
+    pub(crate) fn new(
+        raw_sound: *const crankstart_sys::playdate_sound,
+        raw_subsystem: *const crankstart_sys::playdate_sound_channel,
+        raw_channel: *mut crankstart_sys::SoundChannel,
+        owned: bool,
+    ) -> Result<Self> {
+        ensure!(
+            !raw_subsystem.is_null(),
+            "Null pointer given as subsystem to SoundChannel::new"
+        );
+        ensure!(
+            !raw_channel.is_null(),
+            "Null pointer given as channel to SoundChannel::new"
+        );
+        Ok(Self {
+            raw_sound,
+            raw_subsystem,
+            raw_channel,
+            owned,
+            sources: Vec::new(),
+        })
+    }
+
+    /// Routes `source` through this channel.  The channel holds on to it until it's removed
+    /// or the channel is dropped.
+    pub fn add_source<S: AudioSource + 'static>(&mut self, source: &Rc<S>) -> Result<()> {
+        let result = pd_func_caller!(
+            (*self.raw_subsystem).addSource,
+            self.raw_channel,
+            source.raw_source()
+        )?;
+        ensure!(result == 1, "channel.addSource failed");
+        self.sources.push(source.clone());
+        Ok(())
+    }
+
+    /// Removes `source` from this channel.  Returns whether it was in the channel.
+    pub fn remove_source<S: AudioSource + 'static>(&mut self, source: &Rc<S>) -> Result<bool> {
+        let raw_source = source.raw_source();
+        let result = pd_func_caller!(
+            (*self.raw_subsystem).removeSource,
+            self.raw_channel,
+            raw_source
+        )?;
+        self.sources
+            .retain(|source| source.raw_source() != raw_source);
+        Ok(result == 1)
+    }
+
+    /// Gets the channel's volume, out of 1.
+    pub fn get_volume(&self) -> Result<f32> {
+        pd_func_caller!((*self.raw_subsystem).getVolume, self.raw_channel)
+    }
+
+    /// Sets the channel's volume, out of 1.
+    pub fn set_volume(&self, volume: f32) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).setVolume, self.raw_channel, volume)
+    }
+
+    /// Sets the channel's pan, from -1 (left) to 1 (right).
+    pub fn set_pan(&self, pan: f32) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).setPan, self.raw_channel, pan)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{pd_func_caller, pd_func_caller_log};
 use crankstart_sys::ctypes;
 
+use super::AudioSource;
+
 use anyhow::{anyhow, ensure, Error, Result};
 use cstr_core::CString;
 
@@ -19,6 +21,12 @@ impl Drop for FilePlayer {
     }
 }
 
+impl AudioSource for FilePlayer {
+    fn raw_source(&self) -> *mut crankstart_sys::SoundSource {
+        self.raw_player as *mut crankstart_sys::SoundSource
+    }
+}
+
 // Not implemented: newPlayer (use Sound::get_file_player), setFinishCallback and fadeVolume
 // (waiting on crankstart callback strategy), and setLoopRange (does not seem to do anything).
 impl FilePlayer {
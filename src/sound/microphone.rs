@@ -0,0 +1,117 @@
+use crate::pd_func_caller;
+use crankstart_sys::ctypes;
+
+use alloc::boxed::Box;
+use anyhow::{ensure, Result};
+use core::{ptr, slice};
+
+type MicCallback = Box<dyn FnMut(&[i16]) -> bool>;
+
+// The context of the callback currently given to setMicCallback, so a Microphone whose
+// callback was replaced by another one's doesn't stop the newer callback.
+static mut ACTIVE_MIC_CONTEXT: *mut ctypes::c_void = ptr::null_mut();
+
+/// Internal function that gets passed to the C `setMicCallback()` call.
+///
+/// `context` points at the `MicCallback` owned by the `Microphone` that registered it.
+extern "C" fn mic_callback(
+    context: *mut ctypes::c_void,
+    buffer: *mut i16,
+    length: ctypes::c_int,
+) -> ctypes::c_int {
+    unsafe {
+        let callback = &mut *(context as *mut MicCallback);
+        let samples = if buffer.is_null() || length <= 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(buffer, length as usize)
+        };
+        callback(samples) as ctypes::c_int
+    }
+}
+
+/// Access to audio input from the microphone.
+///
+/// Only one callback can be listening at a time, so starting to listen replaces any callback
+/// set before, including one started by another `Microphone`.  Dropping the `Microphone`
+/// stops listening if its callback is still the one set.
+pub struct Microphone {
+    raw_sound: *const crankstart_sys::playdate_sound,
+    callback: Option<Box<MicCallback>>,
+}
+
+impl core::fmt::Debug for Microphone {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Microphone")
+            .field("listening", &self.is_listening())
+            .finish()
+    }
+}
+
+impl Drop for Microphone {
+    fn drop(&mut self) {
+        if let Err(err) = self.stop_listening() {
+            crate::log_to_console!("Error stopping microphone: {}", err);
+        }
+    }
+}
+
+impl Microphone {
+    pub(crate) fn new(raw_sound: *const crankstart_sys::playdate_sound) -> Result<Self> {
+        ensure!(
+            !raw_sound.is_null(),
+            "Null pointer given as sound to Microphone::new"
+        );
+        Ok(Self {
+            raw_sound,
+            callback: None,
+        })
+    }
+
+    /// Starts calling `callback` with each buffer of mono 16-bit samples recorded at 44.1kHz.
+    /// Return `true` from the callback to keep listening, or `false` to stop.
+    ///
+    /// The headset microphone is used if one is plugged in, unless `force_internal` is set.
+    /// The callback runs on the audio thread, so keep it short.
+    pub fn start_listening<F>(&mut self, force_internal: bool, callback: F) -> Result<()>
+    where
+        F: FnMut(&[i16]) -> bool + 'static,
+    {
+        self.stop_listening()?;
+        let mut callback: Box<MicCallback> = Box::new(Box::new(callback));
+        let context = &mut *callback as *mut MicCallback as *mut ctypes::c_void;
+        pd_func_caller!(
+            (*self.raw_sound).setMicCallback,
+            Some(mic_callback),
+            context,
+            force_internal as ctypes::c_int
+        )?;
+        unsafe { ACTIVE_MIC_CONTEXT = context };
+        self.callback = Some(callback);
+        Ok(())
+    }
+
+    /// Stops calling the callback given to `start_listening`. A callback set since then by
+    /// another `Microphone` is left running.
+    pub fn stop_listening(&mut self) -> Result<()> {
+        if self.is_listening() {
+            pd_func_caller!((*self.raw_sound).setMicCallback, None, ptr::null_mut(), 0)?;
+            unsafe { ACTIVE_MIC_CONTEXT = ptr::null_mut() };
+        }
+        self.callback = None;
+        Ok(())
+    }
+
+    /// Returns whether a callback was given to `start_listening` and hasn't been stopped or
+    /// replaced by another `Microphone`. This doesn't notice a callback that stopped itself
+    /// by returning `false`.
+    pub fn is_listening(&self) -> bool {
+        match &self.callback {
+            Some(callback) => {
+                let context = &**callback as *const MicCallback as *mut ctypes::c_void;
+                context == unsafe { ACTIVE_MIC_CONTEXT }
+            }
+            None => false,
+        }
+    }
+}
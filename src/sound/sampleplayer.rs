@@ -1,7 +1,9 @@
 use crate::{log_to_console, pd_func_caller, pd_func_caller_log};
 use crankstart_sys::ctypes;
 
-use alloc::rc::Rc;
+use super::AudioSource;
+
+use alloc::{rc::Rc, vec::Vec};
 use anyhow::{anyhow, ensure, Error, Result};
 
 /// Note: Make sure you hold on to a SamplePlayer until the sample has played as much as you want,
@@ -23,6 +25,12 @@ impl Drop for SamplePlayer {
     }
 }
 
+impl AudioSource for SamplePlayer {
+    fn raw_source(&self) -> *mut crankstart_sys::SoundSource {
+        self.raw_player as *mut crankstart_sys::SoundSource
+    }
+}
+
 // Not implemented: newPlayer (use Sound::get_sample_player), and setFinishCallback and setLoopCallback
 // (waiting on crankstart callback strategy).
 impl SamplePlayer {
@@ -178,6 +186,9 @@ pub struct AudioSample {
 struct AudioSampleInner {
     raw_subsystem: *const crankstart_sys::playdate_sound_sample,
     raw_audio_sample: *mut crankstart_sys::AudioSample,
+    // Samples made from data point at it rather than copying it, so keep it alive with them.
+    // It's dropped after the sample is freed.
+    _data: Option<Vec<u8>>,
 }
 
 impl Drop for AudioSampleInner {
@@ -187,12 +198,21 @@ impl Drop for AudioSampleInner {
     }
 }
 
-// Not implemented: getData, newSampleBuffer, loadIntoSample, newSampleFromData -
-// only Sound::load_audio_sample for now.
+// Not implemented: getData, loadIntoSample - use Sound::load_audio_sample,
+// Sound::new_sample_buffer, or Sound::new_audio_sample_from_data.
 impl AudioSample {
     pub(crate) fn new(
         raw_subsystem: *const crankstart_sys::playdate_sound_sample,
         raw_audio_sample: *mut crankstart_sys::AudioSample,
+    ) -> Result<Self, Error> {
+        Self::new_with_data(raw_subsystem, raw_audio_sample, None)
+    }
+
+    /// Internal: like `new`, but keeps `data` alive for as long as the sample.
+    pub(crate) fn new_with_data(
+        raw_subsystem: *const crankstart_sys::playdate_sound_sample,
+        raw_audio_sample: *mut crankstart_sys::AudioSample,
+        data: Option<Vec<u8>>,
     ) -> Result<Self, Error> {
         ensure!(
             !raw_subsystem.is_null(),
@@ -206,6 +226,7 @@ impl AudioSample {
             inner: Rc::new(AudioSampleInner {
                 raw_subsystem,
                 raw_audio_sample,
+                _data: data,
             }),
         })
     }
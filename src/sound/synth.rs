@@ -0,0 +1,166 @@
+use crate::{pd_func_caller, pd_func_caller_log};
+
+use super::AudioSource;
+
+use anyhow::{ensure, Result};
+
+pub use crankstart_sys::SoundWaveform;
+
+/// A synthesizer for generating sound effects and music without audio files.
+///
+/// Note: Make sure you hold on to a Synth until its notes have played as much as you want,
+/// because dropping it will stop playback.
+#[derive(Debug)]
+pub struct Synth {
+    raw_subsystem: *const crankstart_sys::playdate_sound_synth,
+    raw_synth: *mut crankstart_sys::PDSynth,
+}
+
+impl Drop for Synth {
+    fn drop(&mut self) {
+        // Use _log to leak rather than fail
+        pd_func_caller_log!((*self.raw_subsystem).freeSynth, self.raw_synth);
+    }
+}
+
+impl AudioSource for Synth {
+    fn raw_source(&self) -> *mut crankstart_sys::SoundSource {
+        self.raw_synth as *mut crankstart_sys::SoundSource
+    }
+}
+
+// Not implemented: newSynth (use Sound::get_synth), setGenerator (waiting on crankstart
+// callback strategy), modulators and parameters (no LFO/envelope/signal wrappers yet), and
+// getEnvelope.
+impl Synth {
+    pub(crate) fn new(
+        raw_subsystem: *const crankstart_sys::playdate_sound_synth,
+        raw_synth: *mut crankstart_sys::PDSynth,
+    ) -> Result<Self> {
+        ensure!(
+            !raw_subsystem.is_null(),
+            "Null pointer given as subsystem to Synth::new"
+        );
+        ensure!(
+            !raw_synth.is_null(),
+            "Null pointer given as synth to Synth::new"
+        );
+        Ok(Self {
+            raw_subsystem,
+            raw_synth,
+        })
+    }
+
+    /// Sets the waveform the synth generates.
+    pub fn set_waveform(&self, waveform: SoundWaveform) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).setWaveform, self.raw_synth, waveform)
+    }
+
+    /// Sets the attack time of the synth's envelope, in seconds: how long a note takes to
+    /// reach full volume.
+    pub fn set_attack_time(&self, attack: f32) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).setAttackTime, self.raw_synth, attack)
+    }
+
+    /// Sets the decay time of the synth's envelope, in seconds: how long a note takes to fall
+    /// from full volume to the sustain level.
+    pub fn set_decay_time(&self, decay: f32) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).setDecayTime, self.raw_synth, decay)
+    }
+
+    /// Sets the sustain level of the synth's envelope, out of 1: the volume a note holds at
+    /// until it's released.
+    pub fn set_sustain_level(&self, sustain: f32) -> Result<()> {
+        pd_func_caller!(
+            (*self.raw_subsystem).setSustainLevel,
+            self.raw_synth,
+            sustain
+        )
+    }
+
+    /// Sets the release time of the synth's envelope, in seconds: how long a note takes to
+    /// fade out after it's released.
+    pub fn set_release_time(&self, release: f32) -> Result<()> {
+        pd_func_caller!(
+            (*self.raw_subsystem).setReleaseTime,
+            self.raw_synth,
+            release
+        )
+    }
+
+    /// Sets the whole envelope at once; see the individual setters for what each part means.
+    pub fn set_adsr(&self, attack: f32, decay: f32, sustain: f32, release: f32) -> Result<()> {
+        self.set_attack_time(attack)?;
+        self.set_decay_time(decay)?;
+        self.set_sustain_level(sustain)?;
+        self.set_release_time(release)
+    }
+
+    /// Transposes the synth's output by the given number of half steps.
+    pub fn set_transpose(&self, half_steps: f32) -> Result<()> {
+        pd_func_caller!(
+            (*self.raw_subsystem).setTranspose,
+            self.raw_synth,
+            half_steps
+        )
+    }
+
+    /// Plays a note at `frequency` Hz, with `velocity` out of 1, for `length` seconds.  If
+    /// `length` is negative, the note plays until `note_off` is called.
+    pub fn play_note(&self, frequency: f32, velocity: f32, length: f32) -> Result<()> {
+        pd_func_caller!(
+            (*self.raw_subsystem).playNote,
+            self.raw_synth,
+            frequency,
+            velocity,
+            length,
+            0
+        )
+    }
+
+    /// Like `play_note`, but takes a MIDI note number, where 60 is middle C.
+    pub fn play_midi_note(&self, note: f32, velocity: f32, length: f32) -> Result<()> {
+        pd_func_caller!(
+            (*self.raw_subsystem).playMIDINote,
+            self.raw_synth,
+            note,
+            velocity,
+            length,
+            0
+        )
+    }
+
+    /// Releases the playing note, letting it fade out over the release time.
+    pub fn note_off(&self) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).noteOff, self.raw_synth, 0)
+    }
+
+    /// Stops the synth immediately, without a release.
+    pub fn stop(&self) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).stop, self.raw_synth)
+    }
+
+    /// Returns whether the synth is currently playing a note.
+    pub fn is_playing(&self) -> Result<bool> {
+        let result = pd_func_caller!((*self.raw_subsystem).isPlaying, self.raw_synth)?;
+        Ok(result == 1)
+    }
+
+    /// Gets the current volume of the left and right audio channels, out of 1.
+    pub fn get_volume(&self) -> Result<(f32, f32)> {
+        let mut left = 0.0;
+        let mut right = 0.0;
+        pd_func_caller!(
+            (*self.raw_subsystem).getVolume,
+            self.raw_synth,
+            &mut left,
+            &mut right,
+        )?;
+        Ok((left, right))
+    }
+
+    /// Sets the volume of the left and right audio channels, out of 1.
+    pub fn set_volume(&self, left: f32, right: f32) -> Result<()> {
+        pd_func_caller!((*self.raw_subsystem).setVolume, self.raw_synth, left, right)
+    }
+}
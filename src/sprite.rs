@@ -11,6 +11,7 @@ use {
         boxed::Box,
         collections::BTreeMap,
         rc::{Rc, Weak},
+        vec::Vec,
     },
     anyhow::{anyhow, Error, Result},
     core::{
@@ -22,7 +23,7 @@ use {
     crankstart_sys::{
         playdate_sprite, LCDRect, LCDSprite, LCDSpriteCollisionFilterProc, SpriteCollisionInfo,
     },
-    euclid::default::Vector2D,
+    euclid::default::{Point2D, Vector2D},
     euclid::{point2, size2, vec2},
     hashbrown::HashMap,
 };
@@ -114,6 +115,94 @@ impl Drop for Collisions {
     }
 }
 
+/// How a sprite reacts when it collides with another during
+/// [Sprite::move_with_collisions()].
+///
+/// This is a Rust-friendly version of [SpriteCollisionResponseType], and converts to and from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollisionResponse {
+    /// Stop at the point of contact, then keep moving along the other sprite's edge
+    Slide,
+    /// Stop at the point of contact
+    Freeze,
+    /// Pass through the other sprite, still reporting the collision
+    Overlap,
+    /// Stop at the point of contact, then move away from the other sprite
+    Bounce,
+}
+
+impl From<CollisionResponse> for SpriteCollisionResponseType {
+    fn from(value: CollisionResponse) -> Self {
+        match value {
+            CollisionResponse::Slide => SpriteCollisionResponseType::kCollisionTypeSlide,
+            CollisionResponse::Freeze => SpriteCollisionResponseType::kCollisionTypeFreeze,
+            CollisionResponse::Overlap => SpriteCollisionResponseType::kCollisionTypeOverlap,
+            CollisionResponse::Bounce => SpriteCollisionResponseType::kCollisionTypeBounce,
+        }
+    }
+}
+
+impl From<SpriteCollisionResponseType> for CollisionResponse {
+    fn from(value: SpriteCollisionResponseType) -> Self {
+        match value {
+            SpriteCollisionResponseType::kCollisionTypeSlide => CollisionResponse::Slide,
+            SpriteCollisionResponseType::kCollisionTypeFreeze => CollisionResponse::Freeze,
+            SpriteCollisionResponseType::kCollisionTypeOverlap => CollisionResponse::Overlap,
+            SpriteCollisionResponseType::kCollisionTypeBounce => CollisionResponse::Bounce,
+        }
+    }
+}
+
+impl CollisionInfo<'_> {
+    /// How the sprite reacted to the collision.
+    pub fn response(&self) -> CollisionResponse {
+        self.info.responseType.into()
+    }
+
+    /// The direction the sprite was pushed back in, with each component -1, 0, or 1.
+    pub fn normal(&self) -> Vector2D<i32> {
+        vec2(self.info.normal.x, self.info.normal.y)
+    }
+
+    /// Whether the sprites were already overlapping before the move.
+    pub fn overlaps(&self) -> bool {
+        self.info.overlaps != 0
+    }
+
+    /// Where the sprite was when it touched the other sprite.
+    pub fn touch(&self) -> Point2D<f32> {
+        point2(self.info.touch.x, self.info.touch.y)
+    }
+
+    /// Copy the parts of the collision that don't borrow from the SDK's collision list.
+    pub fn to_collision(&self) -> Collision {
+        Collision {
+            other: self.other.clone(),
+            response: self.response(),
+            normal: self.normal(),
+            overlaps: self.overlaps(),
+            touch: self.touch(),
+        }
+    }
+}
+
+/// One collision from [Sprite::move_with_collisions_to()].
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct Collision {
+    /// The sprite that was collided with
+    pub other: Sprite,
+    /// How the moving sprite reacted
+    pub response: CollisionResponse,
+    /// The direction the moving sprite was pushed back in
+    pub normal: Vector2D<i32>,
+    /// Whether the sprites were already overlapping before the move
+    pub overlaps: bool,
+    /// Where the moving sprite was when it touched `other`
+    pub touch: Point2D<f32>,
+}
+
 pub struct SpriteInner {
     pub raw_sprite: *mut crankstart_sys::LCDSprite,
     playdate_sprite: *const playdate_sprite,
@@ -447,6 +536,26 @@ impl Sprite {
             .move_with_collisions(goal_x, goal_y)
     }
 
+    /// Moves the sprite towards `goal`, reacting to any sprites in the way according to the
+    /// responses set with [Sprite::set_collision_response_type()], and returns where it ended
+    /// up along with the collisions on the way.
+    ///
+    /// This wraps [Sprite::move_with_collisions()], copying the collisions out so they can be
+    /// kept past the next move.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn move_with_collisions_to(
+        &mut self,
+        goal: Point2D<f32>,
+    ) -> Result<(Point2D<f32>, Vec<Collision>), Error> {
+        let (x, y, collisions) = self.move_with_collisions(goal.x, goal.y)?;
+        let collisions = collisions
+            .iter()
+            .map(|collision| collision.to_collision())
+            .collect();
+        Ok((point2(x, y), collisions))
+    }
+
     pub fn mark_dirty(&mut self) -> Result<(), Error> {
         self.inner
             .try_borrow_mut()
@@ -572,6 +681,14 @@ impl SpriteManager {
         self.sprites.retain(|k, v| v.weak_count() != 0);
         Ok(())
     }
+
+    /// Draws every sprite in the display list without calling their update functions, for
+    /// redrawing while the game is paused.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-sprite.drawSprites)
+    pub fn draw_sprites(&self) -> Result<(), Error> {
+        pd_func_caller!((*self.playdate_sprite).drawSprites)
+    }
 }
 
 /// This is a helper type for drawing text into a sprite.  Drawing text into a sprite is the
@@ -1,15 +1,80 @@
 use {
-    crate::pd_func_caller, alloc::format, anyhow::Error, core::ptr, crankstart_sys::ctypes::c_void,
-    cstr_core::CString,
+    crate::pd_func_caller,
+    alloc::{boxed::Box, format, string::String, vec, vec::Vec},
+    anyhow::{anyhow, ensure, Error},
+    core::ptr,
+    crankstart_sys::ctypes::c_void,
+    cstr_core::{CStr, CString},
 };
 
-use crankstart_sys::ctypes::c_int;
+use crankstart_sys::ctypes::{c_char, c_int};
 pub use crankstart_sys::PDButtons;
 use crankstart_sys::{PDDateTime, PDLanguage, PDPeripherals};
 
 use crate::graphics::Bitmap;
+use euclid::{
+    default::{Vector2D, Vector3D},
+    vec3,
+};
+
+pub mod buttons;
+pub use buttons::{Button, Buttons};
+pub mod combodetector;
+pub use combodetector::ComboDetector;
+pub mod cranktracker;
+pub use cranktracker::CrankTracker;
+pub mod fixedtimestep;
+pub use fixedtimestep::FixedTimestep;
+pub mod formatstring;
+pub use formatstring::FormatArg;
+pub mod formatting;
+pub use formatting::{format_duration_mmss, format_number_grouped, format_time_hhmmss};
+pub mod fpscounter;
+pub use fpscounter::FpsCounter;
+pub mod framebudget;
+pub use framebudget::FrameBudget;
+pub mod inputrecorder;
+pub use inputrecorder::{InputFrame, InputPlayback, InputRecorder};
+pub mod inputsource;
+pub use inputsource::{CrankLike, InputSource, RealCrank, TiltCrank};
+pub mod menuitem;
+pub use menuitem::MenuItem;
+pub mod profiler;
+pub use profiler::{ProfileScope, ProfileStats, Profiler};
+pub mod timer;
+pub use timer::Timer;
+pub mod update;
+pub use update::UpdateResult;
 
 static mut SYSTEM: System = System(ptr::null_mut());
+static mut FPS_COUNTER: FpsCounter = FpsCounter::new();
+static mut EXIT_HOOKS: Vec<Box<dyn FnOnce()>> = Vec::new();
+
+/// Battery voltage above which the Playdate is assumed to be on USB power. A lithium battery
+/// rests at or below 4.2V, so readings over that only happen while the charger is driving it.
+const CHARGING_VOLTAGE: f32 = 4.2;
+
+/// A snapshot of the battery, from [System::get_power_status()].
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PowerStatus {
+    /// Charge level from 0 (empty) to 100 (full)
+    pub percentage: f32,
+    /// Battery voltage, in volts
+    pub voltage: f32,
+    /// Whether the device seems to be charging; see [System::is_charging()]
+    pub charging: bool,
+}
+
+/// Pitch and roll, in degrees, for gravity pointing along `gravity`. See
+/// [System::get_pitch_roll()].
+fn pitch_roll(gravity: Vector3D<f32>) -> (f32, f32) {
+    let pitch = Vector2D::new(gravity.z, gravity.y).angle_from_x_axis();
+    let level = Vector2D::new(gravity.y, gravity.z).length();
+    let roll = Vector2D::new(level, -gravity.x).angle_from_x_axis();
+    (pitch.to_degrees(), roll.to_degrees())
+}
 
 /// Playdate System functions
 #[derive(Clone, Debug)]
@@ -26,6 +91,29 @@ impl System {
         unsafe { SYSTEM.clone() }
     }
 
+    /// Registers `f` to be called once when the game is about to stop running, so it can
+    /// save its state. Hooks run in the order they were registered, when the game receives
+    /// `kEventTerminate` (the player quit the game). Pausing doesn't run them, and neither
+    /// does `kEventLowPower`, since the game resumes after the device wakes; handle that with
+    /// [EventHandler::on_low_power()][crate::EventHandler::on_low_power] instead.
+    ///
+    /// This relies on the event handler installed by [crankstart_game!][crate::crankstart_game].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn on_exit<F: FnOnce() + 'static>(&self, f: F) {
+        unsafe {
+            (*core::ptr::addr_of_mut!(EXIT_HOOKS)).push(Box::new(f));
+        }
+    }
+
+    /// Runs and removes every hook registered with [System::on_exit()].
+    pub(crate) fn run_exit_hooks() {
+        let hooks = unsafe { core::mem::take(&mut *core::ptr::addr_of_mut!(EXIT_HOOKS)) };
+        for hook in hooks {
+            hook();
+        }
+    }
+
     /// Allocates heap space if ptr is NULL, else reallocates the given pointer.
     /// If size is zero, frees the given pointer.
     /// 
@@ -47,6 +135,26 @@ impl System {
         pd_func_caller!((*self.0).setUpdateCallback, f, ptr::null_mut())
     }
 
+    /// Replaces the run loop function with a Rust closure, called once per frame. The closure
+    /// returns whether it drew anything, which tells the system whether to update the display.
+    ///
+    /// This replaces the update callback installed by
+    /// [`crankstart_game!`][crate::crankstart_game], so [Game::update()][crate::Game::update]
+    /// stops being called.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn set_update<F>(&self, f: F) -> Result<(), Error>
+    where
+        F: FnMut() -> UpdateResult + 'static,
+    {
+        let userdata = update::store_update_callback(Box::new(f));
+        pd_func_caller!(
+            (*self.0).setUpdateCallback,
+            Some(update::update_callback),
+            userdata
+        )
+    }
+
     /// `(current, pushed, released)`
     /// 
     /// Sets the value pointed to by current to a bitmask indicating which buttons are currently down.
@@ -69,6 +177,15 @@ impl System {
         Ok((current, pushed, released))
     }
 
+    /// Like [System::get_button_state()], but returns `(current, pushed, released)` as
+    /// [Buttons] so individual buttons can be checked with methods like [Buttons::a()].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_buttons(&self) -> Result<(Buttons, Buttons, Buttons), Error> {
+        let (current, pushed, released) = self.get_button_state()?;
+        Ok((current.into(), pushed.into(), released.into()))
+    }
+
     /// By default, the accelerometer is disabled to save (a small amount of) power.
     /// To use a peripheral, it must first be enabled via this function.
     /// Accelerometer data is not available until the next update cycle after it’s enabled.
@@ -89,6 +206,26 @@ impl System {
         Ok((outx, outy, outz))
     }
 
+    /// Like [System::get_accelerometer()], but returns a vector so the axes can't be mixed up.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_accelerometer_vector(&self) -> Result<Vector3D<f32>, Error> {
+        let (x, y, z) = self.get_accelerometer()?;
+        Ok(vec3(x, y, z))
+    }
+
+    /// Returns the device's `(pitch, roll)` in degrees, worked out from the direction of
+    /// gravity in [System::get_accelerometer_vector()]. Both are zero when the reading is
+    /// `(0, 0, 1)`. Pitch is the rotation around the x axis, from -180 to 180, and roll is
+    /// the tilt towards the x axis, from -90 to 90.
+    ///
+    /// The accelerometer must be enabled with [System::set_peripherals_enabled()] first.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_pitch_roll(&self) -> Result<(f32, f32), Error> {
+        Ok(pitch_roll(self.get_accelerometer_vector()?))
+    }
+
     /// Returns 1 or 0 indicating whether or not the crank is folded into the unit.
     ///
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.isCrankDocked)
@@ -103,6 +240,14 @@ impl System {
         pd_func_caller!((*self.0).getCrankAngle,)
     }
 
+    /// Returns the current position of the crank in radians, in the range 0-2π, measured the
+    /// same way as [System::get_crank_angle()].
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_crank_angle_radians(&self) -> Result<f32, Error> {
+        Ok(self.get_crank_angle()?.to_radians())
+    }
+
     /// Returns the angle change of the crank since the last time this function was called. Negative values are anti-clockwise.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/2.1.1/Inside%20Playdate%20with%20C.html#f-system.getCrankChange)
@@ -140,6 +285,103 @@ impl System {
         }
     }
 
+    /// Formats `args` into `fmt` with the SDK's printf-style formatter, returning the result.
+    ///
+    /// Each conversion in `fmt` takes the next argument, which must be the matching
+    /// [FormatArg] variant: `Int` for `%d`, `%x` and friends, `Float` for `%f`, `%e`, and `%g`,
+    /// and `Str` for `%s`. Flags, width, and precision work as in C, e.g. `%05d`, but `*`
+    /// widths, `%p`, and `%n` aren't supported. Integer lengths such as `%ld` and `%zu` are
+    /// passed as the C type they name, and an error is returned if the value doesn't fit;
+    /// `%Lf` and other lengths without an exact Rust type are rejected.
+    ///
+    /// C can't take a variable number of arguments from Rust, so the format string is split
+    /// at each conversion and formatted a piece at a time.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.formatString)
+    pub fn format_string(&self, fmt: &str, args: &[FormatArg<'_>]) -> Result<String, Error> {
+        use {
+            core::{
+                convert::TryFrom,
+                ffi::{c_long, c_uint, c_ulong},
+            },
+            formatstring::{Conversion, IntWidth},
+        };
+        let mut formatted = String::new();
+        let mut args = args.iter();
+        for segment in formatstring::split_format(fmt)? {
+            let c_text = CString::new(segment.text).map_err(Error::msg)?;
+            let mut ret: *mut c_char = ptr::null_mut();
+            let arg = segment.conversion.and_then(|_| args.next());
+            let len = match (segment.conversion, arg) {
+                (None, _) => pd_func_caller!((*self.0).formatString, &mut ret, c_text.as_ptr())?,
+                (Some(Conversion::Int(width)), Some(FormatArg::Int(value))) => {
+                    let text = c_text.as_ptr();
+                    let out_of_range = || anyhow!("{} doesn't fit {:?}", value, segment.text);
+                    match width {
+                        IntWidth::Int => {
+                            let value = c_int::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                        IntWidth::Long => {
+                            let value = c_long::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                        IntWidth::LongLong => {
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, *value)?
+                        }
+                        IntWidth::Size => {
+                            let value = isize::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                    }
+                }
+                (Some(Conversion::Unsigned(width)), Some(FormatArg::Int(value))) => {
+                    let text = c_text.as_ptr();
+                    let out_of_range = || anyhow!("{} doesn't fit {:?}", value, segment.text);
+                    match width {
+                        IntWidth::Int => {
+                            let value = c_uint::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                        IntWidth::Long => {
+                            let value = c_ulong::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                        IntWidth::LongLong => {
+                            let value = u64::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                        IntWidth::Size => {
+                            let value = usize::try_from(*value).map_err(|_| out_of_range())?;
+                            pd_func_caller!((*self.0).formatString, &mut ret, text, value)?
+                        }
+                    }
+                }
+                (Some(Conversion::Float), Some(FormatArg::Float(value))) => {
+                    pd_func_caller!((*self.0).formatString, &mut ret, c_text.as_ptr(), *value)?
+                }
+                (Some(Conversion::Str), Some(FormatArg::Str(value))) => {
+                    let c_value = CString::new(*value).map_err(Error::msg)?;
+                    let value = c_value.as_ptr();
+                    pd_func_caller!((*self.0).formatString, &mut ret, c_text.as_ptr(), value)?
+                }
+                (Some(_), Some(arg)) => {
+                    return Err(anyhow!("{:?} doesn't match {:?}", arg, segment.text))
+                }
+                (Some(_), None) => return Err(anyhow!("Not enough arguments for {:?}", fmt)),
+            };
+            ensure!(
+                len >= 0 && !ret.is_null(),
+                "formatString failed for {:?}",
+                segment.text
+            );
+            formatted.push_str(&unsafe { CStr::from_ptr(ret) }.to_string_lossy());
+            unsafe { self.realloc(ret as *mut c_void, 0) };
+        }
+        ensure!(args.next().is_none(), "Too many arguments for {:?}", fmt);
+        Ok(formatted)
+    }
+
     /// Calls the log function, outputting an error in red to the console, then pauses execution.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.error)
@@ -187,6 +429,20 @@ impl System {
         Ok(datetime)
     }
 
+    /// Returns the current local wall-clock time: the current time with
+    /// [System::get_timezone_offset()] applied, converted to a [PDDateTime].
+    ///
+    /// The offset is applied to the epoch seconds before converting, so negative offsets and
+    /// day, month, and year rollovers come out right.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_local_datetime(&self) -> Result<PDDateTime, Error> {
+        let (seconds, _) = self.get_seconds_since_epoch()?;
+        let offset = self.get_timezone_offset()?;
+        let local = (seconds as i64 + offset as i64).max(0);
+        self.convert_epoch_to_datetime(local as u32)
+    }
+
     /// Converts the given PDDateTime to an epoch time.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.convertDateTimeToEpoch)
@@ -237,6 +493,19 @@ impl System {
         pd_func_caller!((*self.0).drawFPS, x, y)
     }
 
+    /// Records a frame and returns the frames per second averaged over the last
+    /// [FpsCounter::WINDOW] frames, measured with [System::get_current_time_milliseconds()].
+    ///
+    /// Call this once per update, then draw the value however you like, e.g. with
+    /// [Graphics::draw_text()][crate::graphics::Graphics::draw_text]. Unlike
+    /// [System::draw_fps()], this doesn't draw anything.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn measure_fps(&self) -> Result<f32, Error> {
+        let now = self.get_current_time_milliseconds()?;
+        Ok(unsafe { (*core::ptr::addr_of_mut!(FPS_COUNTER)).tick(now) })
+    }
+
     /// Returns a value from 0-100 denoting the current level of battery charge. 0 = empty; 100 = full.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.getBatteryPercentage)
@@ -251,6 +520,29 @@ impl System {
         pd_func_caller!((*self.0).getBatteryVoltage)
     }
 
+    /// Guesses whether the Playdate is plugged in and charging.
+    ///
+    /// The C API has no charging query, so this reports `true` when the battery voltage is
+    /// above what a battery reaches on its own (4.2V). It can miss the end of a charge, when
+    /// the charger eases off on a full battery.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn is_charging(&self) -> Result<bool, Error> {
+        Ok(self.get_battery_voltage()? > CHARGING_VOLTAGE)
+    }
+
+    /// Gets the battery percentage, voltage, and charging state in one go.
+    ///
+    /// This is a convenience function and not from the original Playdate C API
+    pub fn get_power_status(&self) -> Result<PowerStatus, Error> {
+        let voltage = self.get_battery_voltage()?;
+        Ok(PowerStatus {
+            percentage: self.get_battery_percentage()?,
+            voltage,
+            charging: voltage > CHARGING_VOLTAGE,
+        })
+    }
+
     /// Returns the current language of the system.
     /// 
     /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.getLanguage)
@@ -258,6 +550,114 @@ impl System {
         pd_func_caller!((*self.0).getLanguage)
     }
 
+    /// Adds a menu item to the System Menu. When selected, `callback` is called after the
+    /// menu is dismissed. Up to three custom items can be added to the menu.
+    ///
+    /// The item is removed when the returned [MenuItem] is dropped.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.addMenuItem)
+    pub fn add_menu_item<F>(&self, title: &str, mut callback: F) -> Result<MenuItem, Error>
+    where
+        F: FnMut() + 'static,
+    {
+        let c_title = CString::new(title).map_err(Error::msg)?;
+        let title_ptr = c_title.as_ptr();
+        MenuItem::add(Box::new(move |_| callback()), vec![c_title], Vec::new(), |userdata| {
+            pd_func_caller!(
+                (*self.0).addMenuItem,
+                title_ptr,
+                Some(menuitem::menu_item_callback),
+                userdata
+            )
+        })
+    }
+
+    /// Adds a menu item with a checkmark that the player can toggle. `callback` is called
+    /// with whether the item is now checked after the menu is dismissed.
+    ///
+    /// The item is removed when the returned [MenuItem] is dropped.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.addCheckmarkMenuItem)
+    pub fn add_checkmark_menu_item<F>(
+        &self,
+        title: &str,
+        checked: bool,
+        mut callback: F,
+    ) -> Result<MenuItem, Error>
+    where
+        F: FnMut(bool) + 'static,
+    {
+        let c_title = CString::new(title).map_err(Error::msg)?;
+        let title_ptr = c_title.as_ptr();
+        MenuItem::add(
+            Box::new(move |value| callback(value != 0)),
+            vec![c_title],
+            Vec::new(),
+            |userdata| {
+                pd_func_caller!(
+                    (*self.0).addCheckmarkMenuItem,
+                    title_ptr,
+                    checked as c_int,
+                    Some(menuitem::menu_item_callback),
+                    userdata
+                )
+            },
+        )
+    }
+
+    /// Adds a menu item that cycles through `options` when selected. `callback` is called
+    /// with the index of the chosen option after the menu is dismissed.
+    ///
+    /// The item is removed when the returned [MenuItem] is dropped.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.addOptionsMenuItem)
+    pub fn add_options_menu_item<F>(
+        &self,
+        title: &str,
+        options: &[&str],
+        mut callback: F,
+    ) -> Result<MenuItem, Error>
+    where
+        F: FnMut(usize) + 'static,
+    {
+        let mut strings = vec![CString::new(title).map_err(Error::msg)?];
+        for option in options {
+            strings.push(CString::new(*option).map_err(Error::msg)?);
+        }
+        let title_ptr = strings[0].as_ptr();
+        let mut option_ptrs = strings[1..].iter().map(|option| option.as_ptr()).collect::<Vec<_>>();
+        // Moving the Vec into the MenuItem doesn't move its buffer, so this stays valid
+        let options_ptr = option_ptrs.as_mut_ptr();
+        let option_count = option_ptrs.len() as c_int;
+        MenuItem::add(
+            Box::new(move |value| callback(value.max(0) as usize)),
+            strings,
+            option_ptrs,
+            |userdata| {
+                pd_func_caller!(
+                    (*self.0).addOptionsMenuItem,
+                    title_ptr,
+                    options_ptr,
+                    option_count,
+                    Some(menuitem::menu_item_callback),
+                    userdata
+                )
+            },
+        )
+    }
+
+    /// Removes every custom item from the System Menu.
+    ///
+    /// [MenuItem]s still held afterwards no longer do anything: [MenuItem::is_in_menu()]
+    /// returns `false` and their accessors return an error.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.removeAllMenuItems)
+    pub fn remove_all_menu_items(&self) -> Result<(), Error> {
+        pd_func_caller!((*self.0).removeAllMenuItems)?;
+        unsafe { menuitem::MENU_GENERATION = menuitem::MENU_GENERATION.wrapping_add(1) };
+        Ok(())
+    }
+
     /// A game can optionally provide an image to be displayed alongside the system menu. bitmap must
     /// be a 400x240 LCDBitmap. All important content should be in the left half of the image in an area
     /// 200 pixels wide, as the menu will obscure the rest. The right side of the image will be visible
@@ -272,9 +672,9 @@ impl System {
     pub fn set_menu_image(&self, bitmap: Bitmap, x_offset: i32) -> Result<(), Error> {
         if cfg!(debug_assertions) {
             assert!(x_offset > 0 && x_offset <= 200, "x_offset must be between 0 and 200");
-            let data = bitmap.get_data()?;
-            assert_eq!(bitmap.get_data()?.width, 400, "menu images must be 400x240");
-            assert_eq!(bitmap.get_data()?.height, 240, "menu images must be 400x240");
+            let size = bitmap.get_size()?;
+            assert_eq!(size.width, 400, "menu images must be 400x240");
+            assert_eq!(size.height, 240, "menu images must be 400x240");
         }
         pd_func_caller!((*self.0).setMenuImage, bitmap.inner.borrow().raw_bitmap, x_offset)
     }
@@ -0,0 +1,113 @@
+use crate::system::PDButtons;
+
+/// A single Playdate button, for checking against [Buttons].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Button {
+    Left,
+    Right,
+    Up,
+    Down,
+    B,
+    A,
+}
+
+impl Button {
+    /// Every button, in the order of their bits in [PDButtons].
+    pub const ALL: [Button; 6] = [
+        Button::Left,
+        Button::Right,
+        Button::Up,
+        Button::Down,
+        Button::B,
+        Button::A,
+    ];
+}
+
+impl From<Button> for PDButtons {
+    fn from(button: Button) -> Self {
+        match button {
+            Button::Left => PDButtons::kButtonLeft,
+            Button::Right => PDButtons::kButtonRight,
+            Button::Up => PDButtons::kButtonUp,
+            Button::Down => PDButtons::kButtonDown,
+            Button::B => PDButtons::kButtonB,
+            Button::A => PDButtons::kButtonA,
+        }
+    }
+}
+
+/// A set of buttons, as returned by [System::get_buttons()][crate::system::System::get_buttons].
+///
+/// This wraps the [PDButtons] bitmask so checking for a button doesn't need bit twiddling.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Buttons(pub PDButtons);
+
+impl Buttons {
+    /// A set with no buttons in it.
+    pub const fn empty() -> Self {
+        Buttons(PDButtons(0))
+    }
+
+    /// Returns `true` if `button` is in the set.
+    pub fn contains(&self, button: Button) -> bool {
+        self.0 .0 & PDButtons::from(button).0 != 0
+    }
+
+    /// Returns `true` if no buttons are in the set.
+    pub fn is_empty(&self) -> bool {
+        self.0 .0 == 0
+    }
+
+    /// Iterates over the buttons in the set.
+    pub fn iter(&self) -> impl Iterator<Item = Button> {
+        let buttons = *self;
+        Button::ALL
+            .iter()
+            .copied()
+            .filter(move |&button| buttons.contains(button))
+    }
+
+    pub fn a(&self) -> bool {
+        self.contains(Button::A)
+    }
+
+    pub fn b(&self) -> bool {
+        self.contains(Button::B)
+    }
+
+    pub fn up(&self) -> bool {
+        self.contains(Button::Up)
+    }
+
+    pub fn down(&self) -> bool {
+        self.contains(Button::Down)
+    }
+
+    pub fn left(&self) -> bool {
+        self.contains(Button::Left)
+    }
+
+    pub fn right(&self) -> bool {
+        self.contains(Button::Right)
+    }
+}
+
+impl From<PDButtons> for Buttons {
+    fn from(buttons: PDButtons) -> Self {
+        Buttons(buttons)
+    }
+}
+
+impl From<Buttons> for PDButtons {
+    fn from(buttons: Buttons) -> Self {
+        buttons.0
+    }
+}
+
+impl From<Button> for Buttons {
+    fn from(button: Button) -> Self {
+        Buttons(button.into())
+    }
+}
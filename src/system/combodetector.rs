@@ -0,0 +1,204 @@
+use {
+    crate::system::{PDButtons, System},
+    alloc::vec::Vec,
+    anyhow::Error,
+};
+
+/// Detects a sequence of button presses, like a cheat code.
+///
+/// Call [ComboDetector::update()] once per frame; it returns `true` on the frame the last
+/// button of the sequence is pressed. Pressing any other button drops the progress back to
+/// the longest start of the sequence that the latest presses still match, so mashing Up
+/// three times still counts as the first two buttons of the code below. Taking longer than
+/// the timeout to enter the whole sequence resets the progress.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::system::{ComboDetector, PDButtons, System}};
+/// # fn unlock_everything() {}
+/// # fn main() -> Result<(), Error> {
+/// let mut konami = ComboDetector::new(&[
+///     PDButtons::kButtonUp, PDButtons::kButtonUp,
+///     PDButtons::kButtonDown, PDButtons::kButtonDown,
+///     PDButtons::kButtonLeft, PDButtons::kButtonRight,
+///     PDButtons::kButtonLeft, PDButtons::kButtonRight,
+///     PDButtons::kButtonB, PDButtons::kButtonA,
+/// ])
+/// .with_timeout(5000);
+/// // every frame:
+/// if konami.update(&System::get())? {
+///     unlock_everything();
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComboDetector {
+    sequence: Vec<PDButtons>,
+    // fallback[i] is the length of the longest proper prefix of sequence[..=i] that is also
+    // a suffix of it: how much progress survives a mismatch after i + 1 matched buttons
+    fallback: Vec<usize>,
+    timeout_ms: Option<usize>,
+    // When each button of the current progress was pressed
+    press_times: Vec<usize>,
+}
+
+impl ComboDetector {
+    /// Create a detector for `sequence`, with no time limit.
+    pub fn new(sequence: &[PDButtons]) -> Self {
+        let mut fallback = alloc::vec![0; sequence.len()];
+        let mut matched = 0;
+        for i in 1..sequence.len() {
+            while matched > 0 && sequence[i].0 != sequence[matched].0 {
+                matched = fallback[matched - 1];
+            }
+            if sequence[i].0 == sequence[matched].0 {
+                matched += 1;
+            }
+            fallback[i] = matched;
+        }
+        Self {
+            sequence: sequence.to_vec(),
+            fallback,
+            timeout_ms: None,
+            press_times: Vec::new(),
+        }
+    }
+
+    /// Require the whole sequence to be entered within `timeout_ms` milliseconds of its
+    /// first button.
+    pub fn with_timeout(mut self, timeout_ms: usize) -> Self {
+        self.timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    /// How many buttons of the sequence have been entered so far.
+    pub fn progress(&self) -> usize {
+        self.press_times.len()
+    }
+
+    /// Forget any partially entered sequence.
+    pub fn reset(&mut self) {
+        self.press_times.clear();
+    }
+
+    /// Read the buttons pushed this frame from [System::get_button_state()] and return
+    /// `true` if they complete the sequence.
+    pub fn update(&mut self, system: &System) -> Result<bool, Error> {
+        let (_, pushed, _) = system.get_button_state()?;
+        let now = system.get_current_time_milliseconds()?;
+        Ok(self.update_with(pushed, now))
+    }
+
+    /// Like [ComboDetector::update()], with the buttons pushed this frame and the current
+    /// time in milliseconds supplied by the caller.
+    pub fn update_with(&mut self, pushed: PDButtons, now_ms: usize) -> bool {
+        if let (Some(timeout), Some(&started_at)) = (self.timeout_ms, self.press_times.first()) {
+            if now_ms.saturating_sub(started_at) > timeout {
+                self.reset();
+            }
+        }
+        if pushed.0 == 0 || self.sequence.is_empty() {
+            return false;
+        }
+
+        let mut progress = self.progress();
+        while progress > 0 && pushed.0 != self.sequence[progress].0 {
+            progress = self.fallback[progress - 1];
+        }
+        // The presses still matching are the latest `progress` ones
+        self.press_times.drain(..self.press_times.len() - progress);
+        if pushed.0 != self.sequence[progress].0 {
+            return false;
+        }
+        self.press_times.push(now_ms);
+        if self.press_times.len() == self.sequence.len() {
+            self.reset();
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UP: PDButtons = PDButtons::kButtonUp;
+    const DOWN: PDButtons = PDButtons::kButtonDown;
+    const LEFT: PDButtons = PDButtons::kButtonLeft;
+    const RIGHT: PDButtons = PDButtons::kButtonRight;
+    const A: PDButtons = PDButtons::kButtonA;
+    const B: PDButtons = PDButtons::kButtonB;
+    const KONAMI: [PDButtons; 10] = [UP, UP, DOWN, DOWN, LEFT, RIGHT, LEFT, RIGHT, B, A];
+
+    /// Presses each button on its own frame, 100ms apart from `start_ms`, and returns the
+    /// frames on which the detector fired.
+    fn press(detector: &mut ComboDetector, buttons: &[PDButtons], start_ms: usize) -> Vec<usize> {
+        buttons
+            .iter()
+            .enumerate()
+            .filter(|&(i, &button)| detector.update_with(button, start_ms + i * 100))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn full_sequence_succeeds() {
+        let mut konami = ComboDetector::new(&KONAMI);
+        assert_eq!(press(&mut konami, &KONAMI, 0), [KONAMI.len() - 1]);
+        assert_eq!(konami.progress(), 0);
+    }
+
+    #[test]
+    fn frames_without_presses_keep_progress() {
+        let mut konami = ComboDetector::new(&KONAMI);
+        press(&mut konami, &KONAMI[..3], 0);
+        assert!(!konami.update_with(PDButtons(0), 1000));
+        assert_eq!(konami.progress(), 3);
+    }
+
+    #[test]
+    fn wrong_button_resets_progress() {
+        let mut konami = ComboDetector::new(&KONAMI);
+        press(&mut konami, &[UP, UP, DOWN, A], 0);
+        assert_eq!(konami.progress(), 0);
+        assert!(press(&mut konami, &KONAMI[1..], 1000).is_empty());
+    }
+
+    #[test]
+    fn wrong_button_falls_back_to_the_prefix_it_still_matches() {
+        let mut konami = ComboDetector::new(&KONAMI);
+        press(&mut konami, &[UP, UP, UP], 0);
+        assert_eq!(konami.progress(), 2);
+        let mut presses = alloc::vec![UP];
+        presses.extend_from_slice(&KONAMI);
+        assert_eq!(press(&mut konami, &presses, 1000), [presses.len() - 1]);
+
+        // A wrong button that starts the sequence counts as its first press.
+        let mut detector = ComboDetector::new(&[A, B, A, B, RIGHT]);
+        press(&mut detector, &[A, B, A, B, A], 0);
+        assert_eq!(detector.progress(), 3);
+        assert_eq!(press(&mut detector, &[B, RIGHT], 1000), [1]);
+    }
+
+    #[test]
+    fn timeout_resets_progress() {
+        let mut konami = ComboDetector::new(&KONAMI).with_timeout(500);
+        press(&mut konami, &KONAMI[..3], 0);
+        assert_eq!(konami.progress(), 3);
+        // The 600ms gap runs past the timeout, so this Down starts nothing.
+        assert!(!konami.update_with(DOWN, 800));
+        assert_eq!(konami.progress(), 0);
+        // A fresh attempt within the timeout is counted again.
+        press(&mut konami, &KONAMI[..4], 2000);
+        assert_eq!(konami.progress(), 4);
+    }
+
+    #[test]
+    fn timeout_is_measured_from_the_surviving_prefix() {
+        let mut konami = ComboDetector::new(&KONAMI).with_timeout(1000);
+        // After the third Up the attempt starts at the second, 100ms in.
+        press(&mut konami, &[UP, UP, UP], 0);
+        assert_eq!(press(&mut konami, &KONAMI[2..], 300), [KONAMI.len() - 3]);
+    }
+}
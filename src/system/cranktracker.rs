@@ -0,0 +1,62 @@
+use {crate::system::System, anyhow::Error};
+
+/// Adds up crank rotation over many frames, for games that care how far the crank has been
+/// turned in total rather than where it's pointing.
+///
+/// Each frame, call [CrankTracker::tick()] to add [System::get_crank_change()], or pass a
+/// change from elsewhere to [CrankTracker::add()]. Changes are signed, so the total keeps
+/// counting past a full turn in either direction instead of wrapping at 360.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::system::{CrankTracker, System}};
+/// # fn open_door() {}
+/// # fn main() -> Result<(), Error> {
+/// # let system = System::get();
+/// let mut tracker = CrankTracker::new();
+/// // every frame:
+/// tracker.tick(&system)?;
+/// if tracker.revolutions() >= 3.0 {
+///     open_door();
+///     tracker.reset();
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CrankTracker {
+    degrees: f32,
+}
+
+impl CrankTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `change_degrees` of rotation. Positive values are clockwise.
+    pub fn add(&mut self, change_degrees: f32) {
+        self.degrees += change_degrees;
+    }
+
+    /// Add the crank's change since it was last read, and return that change in degrees.
+    pub fn tick(&mut self, system: &System) -> Result<f32, Error> {
+        let change = system.get_crank_change()?;
+        self.add(change);
+        Ok(change)
+    }
+
+    /// Total rotation in degrees since creation or the last reset.
+    pub fn degrees(&self) -> f32 {
+        self.degrees
+    }
+
+    /// Total rotation in full turns since creation or the last reset. Negative when the
+    /// crank has been turned further anti-clockwise than clockwise.
+    pub fn revolutions(&self) -> f32 {
+        self.degrees / 360.0
+    }
+
+    /// Start counting from zero again.
+    pub fn reset(&mut self) {
+        self.degrees = 0.0;
+    }
+}
@@ -0,0 +1,149 @@
+use {
+    crate::system::System,
+    anyhow::{ensure, Error},
+    core::{ops::Range, time::Duration},
+};
+
+/// Runs game logic at a fixed rate regardless of the frame rate, for deterministic physics.
+///
+/// Each frame, pass the time since the last frame to [FixedTimestep::steps()] and run one
+/// logic step per iteration of the returned range. Time left over carries to the next
+/// frame; [FixedTimestep::alpha()] says how far into the next step it is, for
+/// interpolating what's drawn between the last two logic states.
+///
+/// To avoid a "spiral of death" after a long stall, at most
+/// [`max_steps`][FixedTimestep::with_max_steps] steps are run per frame and any time beyond
+/// that is dropped.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     core::time::Duration,
+/// #     crankstart::system::{FixedTimestep, System},
+/// # };
+/// # struct World;
+/// # impl World {
+/// #     fn physics_step(&mut self) {}
+/// #     fn draw(&self, _alpha: f32) {}
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// # let system = System::get();
+/// # let mut world = World;
+/// let mut timestep = FixedTimestep::new(Duration::from_millis(10))?;
+/// // every frame:
+/// for _ in timestep.tick(&system)? {
+///     world.physics_step();
+/// }
+/// world.draw(timestep.alpha());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedTimestep {
+    step: Duration,
+    max_steps: usize,
+    accumulator: Duration,
+    last_tick_ms: Option<usize>,
+}
+
+impl FixedTimestep {
+    /// Create a timestep running one step every `step`, at most 5 steps per frame.
+    pub fn new(step: Duration) -> Result<Self, Error> {
+        ensure!(!step.is_zero(), "Fixed timestep must be longer than zero");
+        Ok(Self {
+            step,
+            max_steps: 5,
+            accumulator: Duration::ZERO,
+            last_tick_ms: None,
+        })
+    }
+
+    /// Set the most steps run in a single frame.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Add `delta` of frame time and return the range of steps to run this frame.
+    pub fn steps(&mut self, delta: Duration) -> Range<usize> {
+        self.accumulator += delta;
+        let step_nanos = self.step.as_nanos();
+        let mut steps = (self.accumulator.as_nanos() / step_nanos) as usize;
+        if steps > self.max_steps {
+            steps = self.max_steps;
+            self.accumulator =
+                Duration::from_nanos((self.accumulator.as_nanos() % step_nanos) as u64);
+        } else {
+            self.accumulator -= self.step * steps as u32;
+        }
+        0..steps
+    }
+
+    /// Like [FixedTimestep::steps()], measuring the time since the previous call with
+    /// [System::get_current_time_milliseconds()]. The first call runs no steps.
+    pub fn tick(&mut self, system: &System) -> Result<Range<usize>, Error> {
+        let now = system.get_current_time_milliseconds()?;
+        let delta = match self.last_tick_ms.replace(now) {
+            Some(last) => Duration::from_millis(now.saturating_sub(last) as u64),
+            None => Duration::ZERO,
+        };
+        Ok(self.steps(delta))
+    }
+
+    /// How far the leftover time is into the next step, from 0 to 1.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+
+    /// Drop any leftover time, e.g. after unpausing.
+    pub fn reset(&mut self) {
+        self.accumulator = Duration::ZERO;
+        self.last_tick_ms = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn leftover_time_accumulates_across_frames() {
+        let mut timestep = FixedTimestep::new(ms(10)).unwrap();
+        assert_eq!(timestep.steps(ms(6)).len(), 0);
+        assert_eq!(timestep.steps(ms(6)).len(), 1);
+        assert_eq!(timestep.steps(ms(25)).len(), 2);
+        assert_eq!(timestep.steps(ms(3)).len(), 1);
+    }
+
+    #[test]
+    fn steps_are_capped_and_excess_time_dropped() {
+        let mut timestep = FixedTimestep::new(ms(10)).unwrap().with_max_steps(3);
+        assert_eq!(timestep.steps(ms(104)).len(), 3);
+        // Only the partial step survives the cap.
+        assert_eq!(timestep.steps(ms(5)).len(), 0);
+        assert_eq!(timestep.steps(ms(1)).len(), 1);
+    }
+
+    #[test]
+    fn alpha_is_the_fraction_of_the_next_step() {
+        let mut timestep = FixedTimestep::new(ms(10)).unwrap();
+        assert_eq!(timestep.alpha(), 0.0);
+        timestep.steps(ms(25));
+        assert!((timestep.alpha() - 0.5).abs() < 1e-6);
+        timestep.reset();
+        assert_eq!(timestep.alpha(), 0.0);
+    }
+
+    #[test]
+    fn zero_step_is_rejected() {
+        assert!(FixedTimestep::new(Duration::ZERO).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use {
+    alloc::vec::Vec,
+    anyhow::{anyhow, Error},
+};
+
+/// An argument for [System::format_string()][crate::system::System::format_string], matched
+/// in order with the format string's conversions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FormatArg<'a> {
+    /// For `%d`, `%i`, `%u`, `%x`, `%X`, `%o`, and `%c`, with an optional `hh`, `h`, `l`,
+    /// `ll`, `j`, `q`, `z`, or `t` length. The value must fit the C type the length names,
+    /// which is unsigned for `%u`, `%x`, `%X`, and `%o`, so they take values from 0 up to
+    /// e.g. `u32::MAX` for `%x`.
+    Int(i64),
+    /// For `%f`, `%e`, `%g`, and `%a`, in either case, with no length or `l`
+    Float(f64),
+    /// For `%s`
+    Str(&'a str),
+}
+
+/// The size of the C type an integer conversion reads, from its length modifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum IntWidth {
+    /// `int`, for no length, `h`, or `hh`, which are promoted to `int`
+    Int,
+    /// `long`, for `l`
+    Long,
+    /// `long long`, for `ll`, `j`, or `q`
+    LongLong,
+    /// `size_t` or `ptrdiff_t`, for `z` or `t`
+    Size,
+}
+
+/// What kind of argument a conversion takes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Conversion {
+    /// A signed integer, for `%d`, `%i`, and `%c`
+    Int(IntWidth),
+    /// An unsigned integer, for `%u`, `%x`, `%X`, and `%o`
+    Unsigned(IntWidth),
+    Float,
+    Str,
+}
+
+/// Part of a format string holding at most one conversion, so it can be formatted with a
+/// single argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct FormatSegment<'a> {
+    pub text: &'a str,
+    pub conversion: Option<Conversion>,
+}
+
+/// Split `fmt` into segments that each end with one conversion, plus any text after the
+/// last one. `%%` is left in place for the SDK to handle.
+pub(crate) fn split_format(fmt: &str) -> Result<Vec<FormatSegment<'_>>, Error> {
+    const MODIFIERS: &[u8] = b"-+ #0123456789.hlLqjzt";
+    const FLAGS_AND_WIDTH: &[char] = &[
+        '-', '+', ' ', '#', '.', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+    ];
+    let bytes = fmt.as_bytes();
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            i += 1;
+            continue;
+        }
+        if bytes.get(i + 1) == Some(&b'%') {
+            i += 2;
+            continue;
+        }
+        let mut end = i + 1;
+        while end < bytes.len() && MODIFIERS.contains(&bytes[end]) {
+            end += 1;
+        }
+        let length = fmt[i + 1..end].trim_start_matches(FLAGS_AND_WIDTH);
+        // Only lengths whose C type is known exactly are accepted, since the argument is
+        // passed through C varargs and a mismatch would read the wrong number of bytes
+        let width = match length {
+            "" | "h" | "hh" => Some(IntWidth::Int),
+            "l" => Some(IntWidth::Long),
+            "ll" | "j" | "q" => Some(IntWidth::LongLong),
+            "z" | "t" => Some(IntWidth::Size),
+            _ => None,
+        };
+        let conversion = match (bytes.get(end), length, width) {
+            (Some(b'd' | b'i'), _, Some(width)) => Conversion::Int(width),
+            (Some(b'u' | b'x' | b'X' | b'o'), _, Some(width)) => Conversion::Unsigned(width),
+            (Some(b'c'), "", _) => Conversion::Int(IntWidth::Int),
+            (Some(b'f' | b'F' | b'e' | b'E' | b'g' | b'G' | b'a' | b'A'), "" | "l", _) => {
+                Conversion::Float
+            }
+            (Some(b's'), "", _) => Conversion::Str,
+            (Some(_), _, _) => {
+                return Err(anyhow!(
+                    "Unsupported conversion in format string {:?} at byte {}",
+                    fmt,
+                    i
+                ))
+            }
+            (None, _, _) => return Err(anyhow!("Format string {:?} ends mid-conversion", fmt)),
+        };
+        segments.push(FormatSegment {
+            text: &fmt[start..=end],
+            conversion: Some(conversion),
+        });
+        start = end + 1;
+        i = start;
+    }
+    if start < bytes.len() || segments.is_empty() {
+        segments.push(FormatSegment {
+            text: &fmt[start..],
+            conversion: None,
+        });
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conversions(fmt: &str) -> Vec<Option<Conversion>> {
+        split_format(fmt)
+            .unwrap()
+            .iter()
+            .map(|segment| segment.conversion)
+            .collect()
+    }
+
+    #[test]
+    fn splits_after_each_conversion() {
+        let segments = split_format("Score: %05d of %s!").unwrap();
+        let texts: Vec<&str> = segments.iter().map(|segment| segment.text).collect();
+        assert_eq!(texts, ["Score: %05d", " of %s", "!"]);
+        assert_eq!(
+            conversions("Score: %05d of %s!"),
+            [
+                Some(Conversion::Int(IntWidth::Int)),
+                Some(Conversion::Str),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn plain_text_and_percent_signs_are_one_segment() {
+        let segments = split_format("100%% done").unwrap();
+        assert_eq!(
+            segments,
+            [FormatSegment {
+                text: "100%% done",
+                conversion: None
+            }]
+        );
+        assert_eq!(conversions(""), [None]);
+    }
+
+    #[test]
+    fn lengths_pick_the_c_type() {
+        assert_eq!(
+            conversions("%hhd%ld%lld%jd%zd%td"),
+            [
+                Some(Conversion::Int(IntWidth::Int)),
+                Some(Conversion::Int(IntWidth::Long)),
+                Some(Conversion::Int(IntWidth::LongLong)),
+                Some(Conversion::Int(IntWidth::LongLong)),
+                Some(Conversion::Int(IntWidth::Size)),
+                Some(Conversion::Int(IntWidth::Size)),
+            ]
+        );
+        assert_eq!(
+            conversions("%u%08X%lo%zu%c%.2f%lf"),
+            [
+                Some(Conversion::Unsigned(IntWidth::Int)),
+                Some(Conversion::Unsigned(IntWidth::Int)),
+                Some(Conversion::Unsigned(IntWidth::Long)),
+                Some(Conversion::Unsigned(IntWidth::Size)),
+                Some(Conversion::Int(IntWidth::Int)),
+                Some(Conversion::Float),
+                Some(Conversion::Float),
+            ]
+        );
+    }
+
+    #[test]
+    fn inexact_or_unknown_conversions_are_rejected() {
+        for fmt in ["%Lf", "%lc", "%ls", "%hf", "%p", "%n", "%*d", "%5"] {
+            assert!(split_format(fmt).is_err(), "{:?} was accepted", fmt);
+        }
+    }
+}
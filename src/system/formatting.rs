@@ -0,0 +1,40 @@
+//! Formatting helpers for scores, timers and other HUD text.
+//!
+//! These are convenience functions and not from the original Playdate C API
+
+use alloc::{format, string::String};
+
+/// Formats a duration as zero-padded `MM:SS`, e.g. `"03:07"`. Minutes aren't wrapped into
+/// hours, so durations of an hour or more come out as `"61:40"`; use
+/// [format_time_hhmmss()] for those.
+pub fn format_duration_mmss(seconds: u32) -> String {
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Formats a duration as zero-padded `HH:MM:SS`, e.g. `"01:02:03"`. Hours aren't wrapped
+/// into days.
+pub fn format_time_hhmmss(seconds: u32) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Formats `n` with `separator` between each group of three digits, e.g. `1234567` with
+/// `','` gives `"1,234,567"` and `-1000` gives `"-1,000"`.
+pub fn format_number_grouped(n: i64, separator: char) -> String {
+    let digits = format!("{}", n.unsigned_abs());
+    let mut grouped = String::with_capacity(digits.len() * 4 / 3 + 1);
+    if n < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
@@ -0,0 +1,89 @@
+/// Measures frames per second from frame timestamps over a rolling window of recent frames.
+///
+/// [System::measure_fps()][crate::system::System::measure_fps] keeps one of these for you;
+/// create your own if you want to measure something other than the whole update loop, or
+/// supply timestamps from a different clock.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct FpsCounter {
+    timestamps: [usize; FpsCounter::WINDOW],
+    next: usize,
+    count: usize,
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FpsCounter {
+    /// Number of frame timestamps kept in the rolling window.
+    pub const WINDOW: usize = 30;
+
+    pub const fn new() -> Self {
+        Self {
+            timestamps: [0; Self::WINDOW],
+            next: 0,
+            count: 0,
+        }
+    }
+
+    /// Forget all recorded frames.
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.count = 0;
+    }
+
+    /// Record a frame at `now_ms` and return the average frames per second across the
+    /// window. Returns 0 until at least two frames have been recorded.
+    pub fn tick(&mut self, now_ms: usize) -> f32 {
+        self.timestamps[self.next] = now_ms;
+        self.next = (self.next + 1) % Self::WINDOW;
+        self.count = (self.count + 1).min(Self::WINDOW);
+        self.fps()
+    }
+
+    /// The average frames per second across the recorded window, without recording a frame.
+    pub fn fps(&self) -> f32 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        let newest = self.timestamps[(self.next + Self::WINDOW - 1) % Self::WINDOW];
+        let oldest = self.timestamps[(self.next + Self::WINDOW - self.count) % Self::WINDOW];
+        let elapsed = newest.saturating_sub(oldest);
+        if elapsed == 0 {
+            return 0.0;
+        }
+        (self.count - 1) as f32 * 1000.0 / elapsed as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steady_frames_measure_their_rate() {
+        let mut counter = FpsCounter::new();
+        assert_eq!(counter.tick(1000), 0.0);
+        let mut fps = 0.0;
+        // 50 frames at 20ms each, more than fills the window.
+        for frame in 1..=50 {
+            fps = counter.tick(1000 + frame * 20);
+        }
+        assert!((fps - 50.0).abs() < 1e-3, "measured {}", fps);
+        assert_eq!(counter.fps(), fps);
+    }
+
+    #[test]
+    fn reset_forgets_frames() {
+        let mut counter = FpsCounter::new();
+        counter.tick(0);
+        counter.tick(33);
+        counter.reset();
+        assert_eq!(counter.fps(), 0.0);
+        assert_eq!(counter.tick(100), 0.0);
+    }
+}
@@ -0,0 +1,138 @@
+use {
+    crate::{
+        geometry::{ScreenPoint, ScreenRect},
+        graphics::{Graphics, LCDColor, LCDSolidColor},
+        log_to_console,
+        system::System,
+    },
+    anyhow::Error,
+    euclid::size2,
+};
+
+/// A development aid that measures how long each frame takes and reports frames that run
+/// over budget.
+///
+/// Call [FrameBudget::begin()] at the top of your update and [FrameBudget::end()] at the
+/// bottom. Overruns are logged to the console, at most once per
+/// [log interval][FrameBudget::with_log_interval], along with how many frames ran long since
+/// the last message.
+///
+/// Timing uses [System::get_current_time_milliseconds()], so resolution is one millisecond.
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct FrameBudget {
+    budget_ms: usize,
+    log_interval_ms: usize,
+    frame_start: Option<usize>,
+    last_frame_ms: usize,
+    overruns_since_log: usize,
+    last_log: Option<usize>,
+}
+
+impl FrameBudget {
+    /// Create a budget for frames displayed at `refresh_rate` frames per second, the same
+    /// value passed to [Display::set_refresh_rate()][crate::display::Display::set_refresh_rate].
+    pub fn new(refresh_rate: f32) -> Self {
+        let budget_ms = if refresh_rate > 0.0 {
+            (1000.0 / refresh_rate) as usize
+        } else {
+            usize::MAX
+        };
+        Self::with_budget_ms(budget_ms)
+    }
+
+    /// Create a budget of `budget_ms` milliseconds per frame.
+    pub fn with_budget_ms(budget_ms: usize) -> Self {
+        Self {
+            budget_ms,
+            log_interval_ms: 1000,
+            frame_start: None,
+            last_frame_ms: 0,
+            overruns_since_log: 0,
+            last_log: None,
+        }
+    }
+
+    /// Log overruns at most once every `interval_ms` milliseconds. Defaults to one second.
+    pub fn with_log_interval(mut self, interval_ms: usize) -> Self {
+        self.log_interval_ms = interval_ms;
+        self
+    }
+
+    /// The per-frame budget, in milliseconds.
+    pub fn budget_ms(&self) -> usize {
+        self.budget_ms
+    }
+
+    /// How long the most recently completed frame took, in milliseconds.
+    pub fn last_frame_ms(&self) -> usize {
+        self.last_frame_ms
+    }
+
+    /// Returns `true` if the most recently completed frame ran over budget.
+    pub fn is_over_budget(&self) -> bool {
+        self.last_frame_ms > self.budget_ms
+    }
+
+    /// Mark the start of a frame.
+    pub fn begin(&mut self) -> Result<(), Error> {
+        self.begin_at(System::get().get_current_time_milliseconds()?);
+        Ok(())
+    }
+
+    /// Mark the end of a frame, logging if it ran over budget. Returns `true` if it did.
+    pub fn end(&mut self) -> Result<bool, Error> {
+        Ok(self.end_at(System::get().get_current_time_milliseconds()?))
+    }
+
+    /// Like [FrameBudget::begin()], with the current time in milliseconds supplied by the caller.
+    pub fn begin_at(&mut self, now_ms: usize) {
+        self.frame_start = Some(now_ms);
+    }
+
+    /// Like [FrameBudget::end()], with the current time in milliseconds supplied by the caller.
+    ///
+    /// Calling this without a matching [FrameBudget::begin_at()] does nothing.
+    pub fn end_at(&mut self, now_ms: usize) -> bool {
+        let start = match self.frame_start.take() {
+            Some(start) => start,
+            None => return false,
+        };
+        self.last_frame_ms = now_ms.saturating_sub(start);
+        if !self.is_over_budget() {
+            return false;
+        }
+
+        self.overruns_since_log += 1;
+        let should_log = match self.last_log {
+            Some(last_log) => now_ms.saturating_sub(last_log) >= self.log_interval_ms,
+            None => true,
+        };
+        if should_log {
+            log_to_console!(
+                "Frame took {}ms, budget is {}ms ({} over budget since last report)",
+                self.last_frame_ms,
+                self.budget_ms,
+                self.overruns_since_log
+            );
+            self.overruns_since_log = 0;
+            self.last_log = Some(now_ms);
+        }
+        true
+    }
+
+    /// Draw a small square at `position` that is black when the last frame ran over budget
+    /// and white otherwise, for a glanceable on-screen indicator.
+    pub fn draw_indicator(&self, graphics: &Graphics, position: ScreenPoint) -> Result<(), Error> {
+        let color = if self.is_over_budget() {
+            LCDSolidColor::kColorBlack
+        } else {
+            LCDSolidColor::kColorWhite
+        };
+        graphics.fill_rect(
+            ScreenRect::new(position, size2(6, 6)),
+            LCDColor::Solid(color),
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use {
+    crate::{
+        file::{FileSystem, FromBytes, OpenOptions},
+        system::{PDButtons, System},
+    },
+    alloc::vec::Vec,
+    anyhow::{ensure, Error},
+};
+
+/// One frame of player input: the button state returned by [System::get_button_state()]
+/// and the crank angle from [System::get_crank_angle()].
+///
+/// Read input through this type, either live with [InputFrame::read()] or from an
+/// [InputPlayback], to make a game's input recordable and replayable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputFrame {
+    /// Buttons currently held down
+    pub current: PDButtons,
+    /// Buttons pushed since the previous frame
+    pub pushed: PDButtons,
+    /// Buttons released since the previous frame
+    pub released: PDButtons,
+    /// The crank angle, in degrees
+    pub crank_angle: f32,
+}
+
+impl InputFrame {
+    /// Size of a frame in a recording: one byte per button set, then the crank angle as a
+    /// little-endian `f32`.
+    pub const SIZE: usize = 7;
+
+    /// Read this frame's input from the device.
+    pub fn read(system: &System) -> Result<Self, Error> {
+        let (current, pushed, released) = system.get_button_state()?;
+        Ok(Self {
+            current,
+            pushed,
+            released,
+            crank_angle: system.get_crank_angle()?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let angle = self.crank_angle.to_le_bytes();
+        [
+            self.current.0 as u8,
+            self.pushed.0 as u8,
+            self.released.0 as u8,
+            angle[0],
+            angle[1],
+            angle[2],
+            angle[3],
+        ]
+    }
+}
+
+impl FromBytes<{ InputFrame::SIZE }> for InputFrame {
+    fn from_bytes(bytes: &[u8; InputFrame::SIZE]) -> Self {
+        Self {
+            current: PDButtons(bytes[0] as _),
+            pushed: PDButtons(bytes[1] as _),
+            released: PDButtons(bytes[2] as _),
+            crank_angle: f32::from_le_bytes([bytes[3], bytes[4], bytes[5], bytes[6]]),
+        }
+    }
+}
+
+/// Records the player's input every frame so a session can be replayed later with
+/// [InputPlayback], for reproducing bugs and scripted tests.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::system::{InputRecorder, System}};
+/// # fn main() -> Result<(), Error> {
+/// # let system = System::get();
+/// let mut recorder = InputRecorder::new();
+/// // every frame, instead of reading the buttons and crank directly:
+/// let input = recorder.record(&system)?;
+/// // when done:
+/// recorder.save("replays/bug.input")?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InputRecorder {
+    frames: Vec<InputFrame>,
+}
+
+impl InputRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read this frame's input from the device, record it, and return it.
+    pub fn record(&mut self, system: &System) -> Result<InputFrame, Error> {
+        let frame = InputFrame::read(system)?;
+        self.push(frame);
+        Ok(frame)
+    }
+
+    /// Record a frame of input obtained some other way.
+    pub fn push(&mut self, frame: InputFrame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[InputFrame] {
+        &self.frames
+    }
+
+    /// The recording in the format read by [InputPlayback::from_bytes()].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.frames
+            .iter()
+            .flat_map(|frame| frame.to_bytes())
+            .collect()
+    }
+
+    /// Save the recording to the file at `path` in the game's data folder.
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        FileSystem::get().write_file_atomic(path, &self.to_bytes())
+    }
+}
+
+/// Replays input recorded by an [InputRecorder], one frame per call to
+/// [InputPlayback::next_frame()].
+///
+/// This is a convenience type and not from the original Playdate C API
+#[derive(Clone, Debug)]
+pub struct InputPlayback {
+    frames: Vec<InputFrame>,
+    position: usize,
+}
+
+impl InputPlayback {
+    pub fn new(frames: Vec<InputFrame>) -> Self {
+        Self {
+            frames,
+            position: 0,
+        }
+    }
+
+    /// Decode a recording produced by [InputRecorder::to_bytes()].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        ensure!(
+            bytes.len().is_multiple_of(InputFrame::SIZE),
+            "Input recording length {} is not a multiple of the {} byte frame size",
+            bytes.len(),
+            InputFrame::SIZE
+        );
+        let frames = bytes
+            .chunks_exact(InputFrame::SIZE)
+            .map(|chunk| {
+                let mut frame = [0; InputFrame::SIZE];
+                frame.copy_from_slice(chunk);
+                InputFrame::from_bytes(&frame)
+            })
+            .collect();
+        Ok(Self::new(frames))
+    }
+
+    /// Load a recording saved with [InputRecorder::save()].
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let file_sys = FileSystem::get();
+        let size = file_sys.stat(path)?.size() as usize;
+        ensure!(
+            size.is_multiple_of(InputFrame::SIZE),
+            "Input recording {} is not a whole number of frames",
+            path
+        );
+        let file = file_sys.open(path, OpenOptions::ReadData)?;
+        Ok(Self::new(file.read_records(size / InputFrame::SIZE)?))
+    }
+
+    /// The next recorded frame, or `None` once the recording has been played through.
+    pub fn next_frame(&mut self) -> Option<InputFrame> {
+        let frame = self.frames.get(self.position).copied();
+        if frame.is_some() {
+            self.position += 1;
+        }
+        frame
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.frames.len()
+    }
+
+    /// Start playing from the first frame again.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+}
@@ -0,0 +1,119 @@
+use {crate::system::System, anyhow::Error};
+
+/// Something that can be read like the crank: a rotation, in degrees, since the last read.
+///
+/// Implemented by [RealCrank], [TiltCrank] and [InputSource], so gameplay code can take
+/// any of them.
+///
+/// This is a convenience trait and not from the original Playdate C API
+pub trait CrankLike {
+    /// Degrees of rotation since the last call. Positive values are clockwise.
+    fn delta_degrees(&mut self, system: &System) -> Result<f32, Error>;
+}
+
+/// The physical crank, read with [System::get_crank_change()].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RealCrank;
+
+impl CrankLike for RealCrank {
+    fn delta_degrees(&mut self, system: &System) -> Result<f32, Error> {
+        system.get_crank_change()
+    }
+}
+
+/// A stand-in crank driven by tilting the device left and right, for when the crank is
+/// docked or for testing in the Simulator.
+///
+/// Reads the x axis of [System::get_accelerometer()], so the accelerometer must be enabled
+/// with [System::set_peripherals_enabled()] first.
+#[derive(Clone, Copy, Debug)]
+pub struct TiltCrank {
+    degrees_per_frame: f32,
+    dead_zone: f32,
+}
+
+impl Default for TiltCrank {
+    fn default() -> Self {
+        Self::new(10.0)
+    }
+}
+
+impl TiltCrank {
+    /// Create a tilt source that turns `degrees_per_frame` when the device is tilted fully
+    /// on its side, ignoring tilts smaller than `0.1`.
+    pub fn new(degrees_per_frame: f32) -> Self {
+        Self {
+            degrees_per_frame,
+            dead_zone: 0.1,
+        }
+    }
+
+    /// Ignore tilts whose magnitude is less than `dead_zone`, between 0 and 1, so the
+    /// device doesn't drift when held roughly level.
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Convert an accelerometer x reading, where ±1 is tilted fully on its side, to degrees
+    /// of rotation. Tilts past the dead zone are rescaled so rotation starts from zero.
+    pub fn delta_from_tilt(&self, tilt_x: f32) -> f32 {
+        let magnitude = tilt_x.abs().min(1.0);
+        if magnitude < self.dead_zone || self.dead_zone >= 1.0 {
+            return 0.0;
+        }
+        let scaled = (magnitude - self.dead_zone) / (1.0 - self.dead_zone);
+        scaled.copysign(tilt_x) * self.degrees_per_frame
+    }
+}
+
+impl CrankLike for TiltCrank {
+    fn delta_degrees(&mut self, system: &System) -> Result<f32, Error> {
+        let (x, _, _) = system.get_accelerometer()?;
+        Ok(self.delta_from_tilt(x))
+    }
+}
+
+/// A crank-like input that can be switched between the real crank and tilt at runtime,
+/// e.g. from an options menu.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {
+/// #     anyhow::Error,
+/// #     crankstart::system::{InputSource, RealCrank, System, TiltCrank},
+/// #     crankstart_sys::PDPeripherals,
+/// # };
+/// # fn main() -> Result<(), Error> {
+/// # let system = System::get();
+/// # let use_tilt = true;
+/// # let mut angle = 0.0;
+/// let mut input = InputSource::Crank(RealCrank);
+/// if use_tilt {
+///     system.set_peripherals_enabled(PDPeripherals::kAccelerometer)?;
+///     input = InputSource::Tilt(TiltCrank::default());
+/// }
+/// // every frame:
+/// angle += input.delta_degrees(&system)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub enum InputSource {
+    Crank(RealCrank),
+    Tilt(TiltCrank),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Crank(RealCrank)
+    }
+}
+
+impl CrankLike for InputSource {
+    fn delta_degrees(&mut self, system: &System) -> Result<f32, Error> {
+        match self {
+            InputSource::Crank(crank) => crank.delta_degrees(system),
+            InputSource::Tilt(tilt) => tilt.delta_degrees(system),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use {
+    crate::{pd_func_caller, pd_func_caller_log, system::System},
+    alloc::{boxed::Box, string::String, vec::Vec},
+    anyhow::{ensure, Error},
+    core::ptr,
+    crankstart_sys::{
+        ctypes::{c_char, c_int, c_void},
+        PDMenuItem,
+    },
+    cstr_core::{CStr, CString},
+};
+
+/// The userdata given to the SDK for each menu item: the closure to call, and the item
+/// so the trampoline can read its value.
+pub(crate) struct MenuItemCallback {
+    raw_item: *mut PDMenuItem,
+    callback: Box<dyn FnMut(c_int)>,
+}
+
+// Bumped by System::remove_all_menu_items, so items created before then know the SDK has
+// already freed them.
+pub(crate) static mut MENU_GENERATION: u32 = 0;
+
+fn raw_menu_item_value(raw_item: *mut PDMenuItem) -> Result<c_int, Error> {
+    pd_func_caller!((*System::get().0).getMenuItemValue, raw_item)
+}
+
+/// Internal function that gets passed to the C `add*MenuItem()` calls.
+///
+/// `userdata` points at the `MenuItemCallback` owned by the item's [MenuItem].
+pub(crate) extern "C" fn menu_item_callback(userdata: *mut c_void) {
+    unsafe {
+        let data = &mut *(userdata as *mut MenuItemCallback);
+        let value = raw_menu_item_value(data.raw_item).unwrap_or(0);
+        (data.callback)(value);
+    }
+}
+
+/// An item added to the system menu with [System::add_menu_item()],
+/// [System::add_checkmark_menu_item()], or [System::add_options_menu_item()].
+///
+/// The item stays in the menu for as long as this is kept; dropping it removes the item.
+pub struct MenuItem {
+    raw_item: *mut PDMenuItem,
+    generation: u32,
+    _callback: Box<MenuItemCallback>,
+    // The title first, then any options
+    strings: Vec<CString>,
+    // The array of option pointers given to addOptionsMenuItem
+    _option_ptrs: Vec<*const c_char>,
+}
+
+impl core::fmt::Debug for MenuItem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MenuItem")
+            .field("raw_item", &self.raw_item)
+            .finish()
+    }
+}
+
+impl Drop for MenuItem {
+    fn drop(&mut self) {
+        if self.is_in_menu() {
+            pd_func_caller_log!((*System::get().0).removeMenuItem, self.raw_item);
+        }
+    }
+}
+
+impl MenuItem {
+    /// Internal: boxes `callback`, calls `add` with the userdata pointer to register it,
+    /// and wraps the returned item. `strings`, with the title first, and `option_ptrs` are
+    /// kept alive for as long as the item, since the SDK may hold on to them.
+    pub(crate) fn add<F>(
+        callback: Box<dyn FnMut(c_int)>,
+        strings: Vec<CString>,
+        option_ptrs: Vec<*const c_char>,
+        add: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnOnce(*mut c_void) -> Result<*mut PDMenuItem, Error>,
+    {
+        let mut callback = Box::new(MenuItemCallback {
+            raw_item: ptr::null_mut(),
+            callback,
+        });
+        let raw_item = add(&mut *callback as *mut MenuItemCallback as *mut c_void)?;
+        ensure!(
+            !raw_item.is_null(),
+            "Null pointer returned adding menu item"
+        );
+        callback.raw_item = raw_item;
+        Ok(Self {
+            raw_item,
+            generation: unsafe { MENU_GENERATION },
+            _callback: callback,
+            strings,
+            _option_ptrs: option_ptrs,
+        })
+    }
+
+    /// Returns `false` if the item was removed by [System::remove_all_menu_items()].
+    pub fn is_in_menu(&self) -> bool {
+        self.generation == unsafe { MENU_GENERATION }
+    }
+
+    fn ensure_in_menu(&self) -> Result<(), Error> {
+        ensure!(
+            self.is_in_menu(),
+            "Menu item was removed by remove_all_menu_items"
+        );
+        Ok(())
+    }
+
+    /// Gets the item's value: whether a checkmark item is checked (1) or not (0), or the
+    /// index of the selected option of an options item.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.getMenuItemValue)
+    pub fn value(&self) -> Result<i32, Error> {
+        self.ensure_in_menu()?;
+        raw_menu_item_value(self.raw_item)
+    }
+
+    /// Sets the item's value, as described in [MenuItem::value()].
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.setMenuItemValue)
+    pub fn set_value(&self, value: i32) -> Result<(), Error> {
+        self.ensure_in_menu()?;
+        pd_func_caller!((*System::get().0).setMenuItemValue, self.raw_item, value)
+    }
+
+    /// Gets the item's title.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.getMenuItemTitle)
+    pub fn title(&self) -> Result<String, Error> {
+        self.ensure_in_menu()?;
+        let title = pd_func_caller!((*System::get().0).getMenuItemTitle, self.raw_item)?;
+        ensure!(
+            !title.is_null(),
+            "Null pointer returned from getMenuItemTitle"
+        );
+        Ok(unsafe { CStr::from_ptr(title).to_string_lossy().into_owned() })
+    }
+
+    /// Sets the item's title.
+    ///
+    /// [Playdate SDK Reference](https://sdk.play.date/inside-playdate-with-c/#f-system.setMenuItemTitle)
+    pub fn set_title(&mut self, title: &str) -> Result<(), Error> {
+        self.ensure_in_menu()?;
+        let c_title = CString::new(title).map_err(Error::msg)?;
+        pd_func_caller!(
+            (*System::get().0).setMenuItemTitle,
+            self.raw_item,
+            c_title.as_ptr()
+        )?;
+        self.strings[0] = c_title;
+        Ok(())
+    }
+}
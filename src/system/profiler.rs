@@ -0,0 +1,220 @@
+use {
+    crate::{log_to_console, system::System},
+    alloc::vec::Vec,
+};
+
+#[cfg(feature = "profiler")]
+static mut PROFILER: Profiler = Profiler::new();
+
+/// Aggregated timings for one [Profiler] label, in milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProfileStats {
+    /// How many times the label was timed
+    pub count: usize,
+    pub total_ms: usize,
+    pub min_ms: usize,
+    pub max_ms: usize,
+}
+
+impl ProfileStats {
+    pub fn average_ms(&self) -> f32 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total_ms as f32 / self.count as f32
+        }
+    }
+
+    fn add(&mut self, elapsed_ms: usize) {
+        self.min_ms = if self.count == 0 {
+            elapsed_ms
+        } else {
+            self.min_ms.min(elapsed_ms)
+        };
+        self.max_ms = self.max_ms.max(elapsed_ms);
+        self.total_ms += elapsed_ms;
+        self.count += 1;
+    }
+}
+
+/// Collects how long labelled sections of code take across a session, for finding where
+/// frame time goes.
+///
+/// Time a section with [Profiler::scope()], which records into a global profiler when the
+/// returned guard is dropped, then log the results with [Profiler::report()]. Timing uses
+/// [System::get_current_time_milliseconds()], so resolution is one millisecond.
+///
+/// Recording is only compiled in with the `profiler` feature; without it, scopes do nothing
+/// and cost nothing, so they can be left in release builds.
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::system::Profiler};
+/// # fn step_physics() {}
+/// # fn main() -> Result<(), Error> {
+/// # let frame = 0;
+/// // every frame:
+/// let _frame = Profiler::scope("update");
+/// {
+///     let _physics = Profiler::scope("physics");
+///     step_physics();
+/// }
+/// if frame % 300 == 0 {
+///     Profiler::report();
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Profiler {
+    buckets: Vec<(&'static str, ProfileStats)>,
+}
+
+impl Profiler {
+    pub const fn new() -> Self {
+        Self {
+            buckets: Vec::new(),
+        }
+    }
+
+    /// Start timing `label` in the global profiler until the returned guard is dropped.
+    pub fn scope(label: &'static str) -> ProfileScope {
+        #[cfg(feature = "profiler")]
+        let start = System::get().get_current_time_milliseconds().ok();
+        #[cfg(not(feature = "profiler"))]
+        let start = None;
+        ProfileScope { label, start }
+    }
+
+    /// Log the stats for every label in the global profiler to the console.
+    pub fn report() {
+        #[cfg(feature = "profiler")]
+        let profiler = unsafe { &*core::ptr::addr_of!(PROFILER) };
+        #[cfg(feature = "profiler")]
+        for (label, stats) in &profiler.buckets {
+            log_to_console!(
+                "{}: {} calls, avg {:.1}ms, min {}ms, max {}ms, total {}ms",
+                label,
+                stats.count,
+                stats.average_ms(),
+                stats.min_ms,
+                stats.max_ms,
+                stats.total_ms
+            );
+        }
+    }
+
+    /// Forget everything recorded by the global profiler.
+    pub fn reset_global() {
+        #[cfg(feature = "profiler")]
+        unsafe {
+            (*core::ptr::addr_of_mut!(PROFILER)).reset()
+        }
+    }
+
+    /// A copy of the stats for `label` in the global profiler.
+    pub fn global_stats(label: &str) -> Option<ProfileStats> {
+        #[cfg(feature = "profiler")]
+        return unsafe { (*core::ptr::addr_of!(PROFILER)).stats(label) };
+        #[cfg(not(feature = "profiler"))]
+        {
+            let _ = label;
+            None
+        }
+    }
+
+    /// Add one timing of `elapsed_ms` to `label`.
+    pub fn record(&mut self, label: &'static str, elapsed_ms: usize) {
+        // Labels are few, so a linear search beats hashing here.
+        match self.buckets.iter_mut().find(|(name, _)| *name == label) {
+            Some((_, stats)) => stats.add(elapsed_ms),
+            None => {
+                let mut stats = ProfileStats::default();
+                stats.add(elapsed_ms);
+                self.buckets.push((label, stats));
+            }
+        }
+    }
+
+    pub fn stats(&self, label: &str) -> Option<ProfileStats> {
+        self.buckets
+            .iter()
+            .find(|(name, _)| *name == label)
+            .map(|(_, stats)| *stats)
+    }
+
+    pub fn reset(&mut self) {
+        self.buckets.clear();
+    }
+}
+
+/// Times a section of code for the global [Profiler], returned by [Profiler::scope()].
+#[must_use = "the section is timed until the scope is dropped"]
+#[derive(Debug)]
+pub struct ProfileScope {
+    label: &'static str,
+    start: Option<usize>,
+}
+
+impl ProfileScope {
+    /// Record the time from the start of the scope to `now` into `profiler`, once.
+    fn finish(&mut self, profiler: &mut Profiler, now: usize) {
+        if let Some(start) = self.start.take() {
+            profiler.record(self.label, now.saturating_sub(start));
+        }
+    }
+}
+
+impl Drop for ProfileScope {
+    #[cfg(feature = "profiler")]
+    fn drop(&mut self) {
+        if self.start.is_some() {
+            if let Ok(now) = System::get().get_current_time_milliseconds() {
+                self.finish(unsafe { &mut *core::ptr::addr_of_mut!(PROFILER) }, now);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "profiler"))]
+    fn drop(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope_at(label: &'static str, now: usize) -> ProfileScope {
+        ProfileScope {
+            label,
+            start: Some(now),
+        }
+    }
+
+    #[test]
+    fn nested_scopes_record_to_separate_labels() {
+        let mut profiler = Profiler::new();
+        for frame in 0..2 {
+            let base = frame * 100;
+            let mut outer = scope_at("update", base);
+            let mut inner = scope_at("physics", base + 5);
+            inner.finish(&mut profiler, base + 12 + frame);
+            outer.finish(&mut profiler, base + 20);
+        }
+
+        let physics = profiler.stats("physics").unwrap();
+        assert_eq!(physics.count, 2);
+        assert_eq!((physics.min_ms, physics.max_ms, physics.total_ms), (7, 8, 15));
+        let update = profiler.stats("update").unwrap();
+        assert_eq!(update.count, 2);
+        assert_eq!((update.min_ms, update.max_ms, update.total_ms), (20, 20, 40));
+        assert_eq!(profiler.stats("render"), None);
+    }
+
+    #[test]
+    fn finished_scope_records_once() {
+        let mut profiler = Profiler::new();
+        let mut scope = scope_at("update", 10);
+        scope.finish(&mut profiler, 15);
+        scope.finish(&mut profiler, 30);
+        assert_eq!(profiler.stats("update").unwrap().count, 1);
+    }
+}
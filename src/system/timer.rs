@@ -0,0 +1,60 @@
+use {crate::system::System, anyhow::Error};
+
+/// Measures time since it was started, for "has N seconds passed" checks.
+///
+/// Timers read [System::get_current_time_milliseconds()], so any number of them can run
+/// at once without touching the single timer behind [System::reset_elapsed_time()].
+///
+/// This is a convenience type and not from the original Playdate C API
+/// ```no_run
+/// # use {anyhow::Error, crankstart::system::Timer};
+/// # fn fire() {}
+/// # fn main() -> Result<(), Error> {
+/// # let fire_pressed = true;
+/// let mut cooldown = Timer::new()?;
+/// // every frame:
+/// if fire_pressed && cooldown.has_elapsed(0.5)? {
+///     fire();
+///     cooldown.restart()?;
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timer {
+    start_ms: usize,
+}
+
+impl Timer {
+    /// Start a timer now.
+    pub fn new() -> Result<Self, Error> {
+        let now = System::get().get_current_time_milliseconds()?;
+        Ok(Self::starting_at(now))
+    }
+
+    /// Start a timer at `start_ms`, as returned by [System::get_current_time_milliseconds()].
+    pub fn starting_at(start_ms: usize) -> Self {
+        Self { start_ms }
+    }
+
+    /// Seconds since the timer was started.
+    pub fn elapsed(&self) -> Result<f32, Error> {
+        let now = System::get().get_current_time_milliseconds()?;
+        Ok(self.elapsed_at(now))
+    }
+
+    /// Seconds between the timer's start and `now_ms`.
+    pub fn elapsed_at(&self, now_ms: usize) -> f32 {
+        now_ms.saturating_sub(self.start_ms) as f32 / 1000.0
+    }
+
+    /// Returns `true` once at least `seconds` have passed since the timer was started.
+    pub fn has_elapsed(&self, seconds: f32) -> Result<bool, Error> {
+        Ok(self.elapsed()? >= seconds)
+    }
+
+    /// Start timing again from now.
+    pub fn restart(&mut self) -> Result<(), Error> {
+        self.start_ms = System::get().get_current_time_milliseconds()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use {
+    alloc::{boxed::Box, vec::Vec},
+    core::ptr::addr_of_mut,
+    crankstart_sys::ctypes::{c_int, c_void},
+};
+
+/// What an update closure given to [System::set_update()][crate::system::System::set_update]
+/// did this frame, which tells the system whether to refresh the display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateResult {
+    /// Something was drawn, so the display should be updated.
+    Updated,
+    /// Nothing changed, so the display can be left alone.
+    NoChange,
+}
+
+impl From<UpdateResult> for c_int {
+    fn from(result: UpdateResult) -> Self {
+        match result {
+            UpdateResult::Updated => 1,
+            UpdateResult::NoChange => 0,
+        }
+    }
+}
+
+pub(crate) type UpdateCallback = Box<dyn FnMut() -> UpdateResult>;
+
+// The installed closure, kept alive here while the SDK holds a pointer to it. Closures it
+// replaced wait in RETIRED_UPDATE_CALLBACKS until the next frame starts, so a closure that
+// installs new ones is never freed while it's still running.
+static mut UPDATE_CALLBACK: Option<Box<UpdateCallback>> = None;
+// Boxed so a running closure, reached through its userdata pointer, doesn't move
+#[allow(clippy::vec_box)]
+static mut RETIRED_UPDATE_CALLBACKS: Vec<Box<UpdateCallback>> = Vec::new();
+
+/// Stores `callback` and returns the userdata pointer to register with [update_callback].
+pub(crate) fn store_update_callback(callback: UpdateCallback) -> *mut c_void {
+    let mut callback = Box::new(callback);
+    let userdata = &mut *callback as *mut UpdateCallback as *mut c_void;
+    unsafe {
+        if let Some(previous) = (*addr_of_mut!(UPDATE_CALLBACK)).replace(callback) {
+            (*addr_of_mut!(RETIRED_UPDATE_CALLBACKS)).push(previous);
+        }
+    }
+    userdata
+}
+
+/// Internal function that gets passed to the C `setUpdateCallback()` call.
+///
+/// `userdata` points at the `UpdateCallback` stored by [store_update_callback].
+pub(crate) extern "C" fn update_callback(userdata: *mut c_void) -> c_int {
+    // No closure is running between frames, so the replaced ones can be freed now
+    unsafe { (*addr_of_mut!(RETIRED_UPDATE_CALLBACKS)).clear() };
+    let callback = unsafe { &mut *(userdata as *mut UpdateCallback) };
+    callback().into()
+}